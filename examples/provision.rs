@@ -0,0 +1,60 @@
+//! Mise en service des servos neufs, un par un
+//!
+//! Usage: cargo run --example provision -- <plan.toml> [labels.jsonl]
+//!
+//! Exemple de plan (TOML):
+//!
+//!     ids = [1, 2, 3, 4, 5, 6]
+//!     baud_rate = 0          # STS_1M
+//!     position_offset = 0
+//!     angle_limits = [0, 4095]
+//!     mode = 0
+
+use st3215::{ProvisioningPlan, ServoLabel, ST3215};
+use std::io::{self, BufRead, Write};
+
+fn main() -> Result<(), String> {
+    let mut args = std::env::args().skip(1);
+    let plan_path = args
+        .next()
+        .ok_or("Usage: provision <plan.toml> [labels.jsonl]")?;
+    let labels_path = args.next().unwrap_or_else(|| "labels.jsonl".to_string());
+
+    // Remplacer "/dev/ttyUSB0" par le port série approprié
+    let device = std::env::var("SERVO_PORT").unwrap_or_else(|_| "/dev/ttyUSB0".to_string());
+
+    let plan = ProvisioningPlan::load(&plan_path)?;
+    let mut labels = ServoLabel::load_all(&labels_path)?;
+
+    println!("Connexion au port série: {}", device);
+    let controller = ST3215::new(&device)?;
+
+    let stdin = io::stdin();
+    while let Some(new_id) = plan.next_id(&labels) {
+        print!(
+            "\nBrancher un servo neuf seul sur le bus (ID {} attendu), puis Entrée (q pour quitter): ",
+            new_id
+        );
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).map_err(|e| e.to_string())? == 0 || line.trim() == "q" {
+            break;
+        }
+
+        match controller.provision(&plan, new_id) {
+            Ok(label) => {
+                label.append_to(&labels_path)?;
+                println!(
+                    "✓ Servo {} ({}, firmware {}) configuré, étiquette enregistrée dans {}",
+                    label.id, label.model, label.firmware, labels_path
+                );
+                labels.push(label);
+            }
+            Err(e) => println!("✗ Échec: {}", e),
+        }
+    }
+
+    println!("\n{} servo(s) mis en service", labels.len());
+    Ok(())
+}
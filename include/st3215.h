@@ -49,6 +49,8 @@
 
 #define STS_END 0
 
+#define SCS_END 1
+
 #define INST_PING 1
 
 #define INST_READ 2
@@ -63,6 +65,10 @@
 
 #define INST_SYNC_READ 130
 
+#define RETURN_DELAY_UNIT_US 2
+
+#define MAX_RETURN_DELAY_US (254 * RETURN_DELAY_UNIT_US)
+
 #define STS_1M 0
 
 #define STS_0_5M 1
@@ -79,6 +85,10 @@
 
 #define STS_38400 7
 
+#define STS_FIRMWARE_MAJOR 0
+
+#define STS_FIRMWARE_MINOR 1
+
 #define STS_MODEL_L 3
 
 #define STS_MODEL_H 4
@@ -87,6 +97,10 @@
 
 #define STS_BAUD_RATE 6
 
+#define STS_RETURN_DELAY 7
+
+#define STS_RESPONSE_LEVEL 8
+
 #define STS_MIN_ANGLE_LIMIT_L 9
 
 #define STS_MIN_ANGLE_LIMIT_H 10
@@ -95,16 +109,60 @@
 
 #define STS_MAX_ANGLE_LIMIT_H 12
 
+#define STS_MAX_TEMPERATURE 13
+
+#define STS_MAX_VOLTAGE 14
+
+#define STS_MIN_VOLTAGE 15
+
+#define STS_MAX_TORQUE_L 16
+
+#define STS_MAX_TORQUE_H 17
+
+#define STS_PHASE 18
+
+#define STS_UNLOADING_CONDITION 19
+
+#define STS_LED_ALARM 20
+
+#define STS_P_COEF 21
+
+#define STS_D_COEF 22
+
+#define STS_I_COEF 23
+
+#define STS_MIN_STARTUP_FORCE_L 24
+
+#define STS_MIN_STARTUP_FORCE_H 25
+
 #define STS_CW_DEAD 26
 
 #define STS_CCW_DEAD 27
 
+#define STS_PROTECTION_CURRENT_L 28
+
+#define STS_PROTECTION_CURRENT_H 29
+
+#define STS_ANGULAR_RESOLUTION 30
+
 #define STS_OFS_L 31
 
 #define STS_OFS_H 32
 
 #define STS_MODE 33
 
+#define STS_PROTECTIVE_TORQUE 34
+
+#define STS_PROTECTION_TIME 35
+
+#define STS_OVERLOAD_TORQUE 36
+
+#define STS_SPEED_P_COEF 37
+
+#define STS_OVERCURRENT_PROTECTION_TIME 38
+
+#define STS_SPEED_I_COEF 39
+
 #define STS_TORQUE_ENABLE 40
 
 #define STS_ACC 41
@@ -121,6 +179,10 @@
 
 #define STS_GOAL_SPEED_H 47
 
+#define STS_TORQUE_LIMIT_L 48
+
+#define STS_TORQUE_LIMIT_H 49
+
 #define STS_LOCK 55
 
 #define STS_PRESENT_POSITION_L 56
@@ -139,6 +201,8 @@
 
 #define STS_PRESENT_TEMPERATURE 63
 
+#define STS_ASYNC_WRITE_FLAG 64
+
 #define STS_STATUS 65
 
 #define STS_MOVING 66
@@ -147,6 +211,74 @@
 
 #define STS_PRESENT_CURRENT_H 70
 
+#define SCS_MIN_POSITION 0
+
+#define SCS_MAX_POSITION 1023
+
+#define SCS_MAX_SPEED 1500
+
+#define SCS_VERSION_L 3
+
+#define SCS_VERSION_H 4
+
+#define SCS_ID 5
+
+#define SCS_BAUD_RATE 6
+
+#define SCS_RETURN_DELAY 7
+
+#define SCS_RESPONSE_LEVEL 8
+
+#define SCS_MIN_ANGLE_LIMIT_L 9
+
+#define SCS_MIN_ANGLE_LIMIT_H 10
+
+#define SCS_MAX_ANGLE_LIMIT_L 11
+
+#define SCS_MAX_ANGLE_LIMIT_H 12
+
+#define SCS_CW_DEAD 26
+
+#define SCS_CCW_DEAD 27
+
+#define SCS_TORQUE_ENABLE 40
+
+#define SCS_GOAL_POSITION_L 42
+
+#define SCS_GOAL_POSITION_H 43
+
+#define SCS_GOAL_TIME_L 44
+
+#define SCS_GOAL_TIME_H 45
+
+#define SCS_GOAL_SPEED_L 46
+
+#define SCS_GOAL_SPEED_H 47
+
+#define SCS_LOCK 48
+
+#define SCS_PRESENT_POSITION_L 56
+
+#define SCS_PRESENT_POSITION_H 57
+
+#define SCS_PRESENT_SPEED_L 58
+
+#define SCS_PRESENT_SPEED_H 59
+
+#define SCS_PRESENT_LOAD_L 60
+
+#define SCS_PRESENT_LOAD_H 61
+
+#define SCS_PRESENT_VOLTAGE 62
+
+#define SCS_PRESENT_TEMPERATURE 63
+
+#define SCS_MOVING 66
+
+#define SCS_PRESENT_CURRENT_L 69
+
+#define SCS_PRESENT_CURRENT_H 70
+
 /**
  * Handle opaque pour ST3215
  */
@@ -171,6 +303,22 @@ struct ST3215Handle *st3215_new(const char *device);
  */
 void st3215_free(struct ST3215Handle *handle);
 
+/**
+ * Déclarer la variante de protocole d'un servo
+ *
+ * # Arguments
+ * * `handle` - Handle ST3215
+ * * `servo_id` - ID du servo
+ * * `protocol` - 0 pour STS (little-endian), 1 pour SCS (big-endian)
+ *
+ * # Retour
+ * 0 en cas de succès, -1 en cas d'erreur
+ *
+ * # Safety
+ * `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+ */
+int32_t st3215_set_protocol(struct ST3215Handle *handle, uint8_t servo_id, int32_t protocol);
+
 /**
  * Vérifier la présence d'un servo
  *
@@ -215,6 +363,32 @@ int32_t st3215_move_to(struct ST3215Handle *handle,
                        uint16_t speed,
                        uint8_t acceleration);
 
+/**
+ * Déplacer plusieurs servos simultanément (une seule trame SYNC_WRITE)
+ *
+ * # Arguments
+ * * `handle` - Handle ST3215
+ * * `servo_ids` - Tableau des IDs des servos
+ * * `positions` - Tableau des positions cibles
+ * * `speeds` - Tableau des vitesses
+ * * `accelerations` - Tableau des accélérations
+ * * `count` - Nombre de servos (taille de chaque tableau)
+ *
+ * # Retour
+ * 0 en cas de succès, -1 en cas d'erreur
+ *
+ * # Safety
+ * `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré.
+ * Chaque tableau doit contenir au moins `count` éléments valides; les pointeurs doivent être
+ * non NULL même si `count` vaut 0.
+ */
+int32_t st3215_move_many(struct ST3215Handle *handle,
+                         const uint8_t *servo_ids,
+                         const uint16_t *positions,
+                         const uint16_t *speeds,
+                         const uint8_t *accelerations,
+                         uintptr_t count);
+
 /**
  * Lire la position actuelle d'un servo
  *
@@ -320,6 +494,36 @@ int32_t st3215_is_moving(struct ST3215Handle *handle, uint8_t servo_id);
  */
 int32_t st3215_enable_torque(struct ST3215Handle *handle, uint8_t servo_id, int32_t enable);
 
+/**
+ * Limiter le couple d'un servo
+ *
+ * # Arguments
+ * * `handle` - Handle ST3215
+ * * `servo_id` - ID du servo (254 pour tous les servos)
+ * * `percent` - Couple maximal autorisé (0-100 %)
+ *
+ * # Retour
+ * 0 en cas de succès, -1 en cas d'erreur
+ *
+ * # Safety
+ * `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+ */
+int32_t st3215_set_torque_limit(struct ST3215Handle *handle, uint8_t servo_id, float percent);
+
+/**
+ * Arrêt d'urgence: couper le couple de tous les servos
+ *
+ * # Arguments
+ * * `handle` - Handle ST3215
+ *
+ * # Retour
+ * 0 en cas de succès, -1 en cas d'erreur
+ *
+ * # Safety
+ * `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+ */
+int32_t st3215_emergency_stop(struct ST3215Handle *handle);
+
 /**
  * Obtenir la version de la bibliothèque
  *
@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+/// Priorité d'une transaction sur le bus (la plus haute est servie en premier)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Diagnostics, balayage du bus, configuration
+    Diagnostics = 0,
+    /// Lecture périodique des capteurs
+    Telemetry = 1,
+    /// Boucle de contrôle (valeur par défaut)
+    #[default]
+    Control = 2,
+    /// Arrêt d'urgence
+    EmergencyStop = 3,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    priority: Priority,
+    deadline: Option<Instant>,
+    ticket: u64,
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap est un tas max: le plus grand est servi en premier
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| match (self.deadline, other.deadline) {
+                (Some(a), Some(b)) => b.cmp(&a),
+                (Some(_), None) => Ordering::Greater,
+                (None, Some(_)) => Ordering::Less,
+                (None, None) => Ordering::Equal,
+            })
+            .then_with(|| other.ticket.cmp(&self.ticket))
+    }
+}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.ticket == other.ticket
+    }
+}
+
+impl Eq for Waiter {}
+
+#[derive(Debug, Default)]
+struct ArbiterState {
+    busy: bool,
+    next_ticket: u64,
+    waiting: BinaryHeap<Waiter>,
+}
+
+/// Arbitre d'accès au bus partagé entre plusieurs threads
+///
+/// Les transactions en attente sont servies par priorité, puis par échéance la plus proche,
+/// puis dans l'ordre d'arrivée. L'accès est rendu par le `Drop` de `BusGuard`, y compris
+/// lorsqu'un appelant panique: le bus ne peut pas rester bloqué.
+///
+/// L'arbitre n'est pas réentrant: un thread qui détient déjà un `BusGuard` et en redemande un
+/// se bloque indéfiniment (ou jusqu'à son échéance).
+#[derive(Debug, Default)]
+pub struct BusArbiter {
+    state: Mutex<ArbiterState>,
+    available: Condvar,
+}
+
+/// Accès exclusif au bus, rendu automatiquement à la destruction
+#[derive(Debug)]
+pub struct BusGuard<'a> {
+    arbiter: &'a BusArbiter,
+}
+
+impl Drop for BusGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.arbiter.lock_state();
+        state.busy = false;
+        drop(state);
+        self.arbiter.available.notify_all();
+    }
+}
+
+impl BusArbiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Obtenir l'accès exclusif au bus
+    /// deadline: instant au-delà duquel la transaction est abandonnée (None = attendre indéfiniment)
+    ///
+    /// Retourne None si l'échéance est dépassée avant l'obtention du bus
+    pub fn acquire(&self, priority: Priority, deadline: Option<Instant>) -> Option<BusGuard<'_>> {
+        let mut state = self.lock_state();
+
+        if !state.busy && state.waiting.is_empty() {
+            state.busy = true;
+            return Some(BusGuard { arbiter: self });
+        }
+
+        let ticket = state.next_ticket;
+        state.next_ticket += 1;
+        state.waiting.push(Waiter {
+            priority,
+            deadline,
+            ticket,
+        });
+
+        loop {
+            if !state.busy && state.waiting.peek().map(|w| w.ticket) == Some(ticket) {
+                state.waiting.pop();
+                state.busy = true;
+                return Some(BusGuard { arbiter: self });
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        state.waiting.retain(|w| w.ticket != ticket);
+                        drop(state);
+                        // Le suivant dans la file est peut-être servable
+                        self.available.notify_all();
+                        return None;
+                    }
+                    state = self
+                        .available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0;
+                }
+                None => {
+                    state = self.available.wait(state).unwrap_or_else(PoisonError::into_inner);
+                }
+            }
+        }
+    }
+
+    /// Nombre de transactions en attente du bus
+    pub fn waiting(&self) -> usize {
+        self.lock_state().waiting.len()
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, ArbiterState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    /// Faire attendre un thread sur le bus, puis attendre qu'il soit bien dans la file
+    fn enqueue(
+        arbiter: &Arc<BusArbiter>,
+        order: &Arc<Mutex<Vec<&'static str>>>,
+        name: &'static str,
+        priority: Priority,
+        deadline: Option<Instant>,
+    ) -> thread::JoinHandle<()> {
+        let waiting = arbiter.waiting();
+        let (arbiter_clone, order) = (Arc::clone(arbiter), Arc::clone(order));
+        let handle = thread::spawn(move || {
+            let _guard = arbiter_clone.acquire(priority, deadline).unwrap();
+            order.lock().unwrap().push(name);
+        });
+        while arbiter.waiting() == waiting {
+            thread::sleep(Duration::from_millis(1));
+        }
+        handle
+    }
+
+    #[test]
+    fn serves_by_priority_then_deadline_then_arrival() {
+        let arbiter = Arc::new(BusArbiter::new());
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let guard = arbiter.acquire(Priority::Control, None).unwrap();
+
+        let far = Instant::now() + Duration::from_secs(60);
+        let near = Instant::now() + Duration::from_secs(30);
+        let handles = vec![
+            enqueue(&arbiter, &order, "diagnostics", Priority::Diagnostics, None),
+            enqueue(&arbiter, &order, "control", Priority::Control, None),
+            enqueue(&arbiter, &order, "control far", Priority::Control, Some(far)),
+            enqueue(&arbiter, &order, "control near", Priority::Control, Some(near)),
+            enqueue(&arbiter, &order, "emergency", Priority::EmergencyStop, None),
+            enqueue(&arbiter, &order, "control late", Priority::Control, None),
+        ];
+
+        drop(guard);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            ["emergency", "control near", "control far", "control", "control late", "diagnostics"]
+        );
+    }
+
+    #[test]
+    fn expired_deadline_gives_up_and_leaves_queue() {
+        let arbiter = BusArbiter::new();
+        let _guard = arbiter.acquire(Priority::Control, None).unwrap();
+
+        let deadline = Instant::now() + Duration::from_millis(20);
+        assert!(arbiter.acquire(Priority::EmergencyStop, Some(deadline)).is_none());
+        assert_eq!(arbiter.waiting(), 0);
+    }
+
+    #[test]
+    fn guard_releases_bus_on_drop() {
+        let arbiter = BusArbiter::new();
+        drop(arbiter.acquire(Priority::Control, None).unwrap());
+        assert!(arbiter
+            .acquire(Priority::Diagnostics, Some(Instant::now()))
+            .is_some());
+    }
+}
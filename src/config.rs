@@ -0,0 +1,197 @@
+use crate::control_table::Register;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Configuration sauvegardée d'un servo
+/// Les registres sont indexés par nom (`Register::name`), en unités du registre
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServoConfig {
+    pub id: u8,
+    /// Nom du modèle au moment de la sauvegarde (informatif)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub registers: BTreeMap<String, i32>,
+}
+
+/// Configuration sauvegardée de tous les servos d'un bus
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BusConfig {
+    #[serde(default, rename = "servo")]
+    pub servos: Vec<ServoConfig>,
+}
+
+impl BusConfig {
+    /// Configuration sauvegardée pour un ID donné
+    pub fn servo(&self, id: u8) -> Option<&ServoConfig> {
+        self.servos.iter().find(|servo| servo.id == id)
+    }
+
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| format!("Could not serialize configuration: {}", e))
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| format!("Invalid TOML configuration: {}", e))
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Could not serialize configuration: {}", e))
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| format!("Invalid JSON configuration: {}", e))
+    }
+
+    /// Enregistrer la configuration dans un fichier
+    /// Le format est choisi selon l'extension: `.json` pour JSON, TOML sinon
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let text = if is_json(path) { self.to_json()? } else { self.to_toml()? };
+        fs::write(path, text).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    /// Charger une configuration depuis un fichier (`.json` ou TOML)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        if is_json(path) {
+            Self::from_json(&text)
+        } else {
+            Self::from_toml(&text)
+        }
+    }
+}
+
+pub(crate) fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Modification d'un registre prévue par `ST3215::plan`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterChange {
+    pub id: u8,
+    pub register: Register,
+    /// Valeur actuelle lue sur le servo
+    pub current: i32,
+    /// Valeur souhaitée
+    pub desired: i32,
+}
+
+/// Différence entre l'état souhaité et l'état actuel du bus
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<RegisterChange>,
+}
+
+impl Plan {
+    /// Le bus est-il déjà dans l'état souhaité ?
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Nombre d'écritures EEPROM prévues
+    pub fn eeprom_writes(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.register.spec().is_eeprom())
+            .count()
+    }
+}
+
+impl fmt::Display for RegisterChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spec = self.register.spec();
+        write!(f, "servo {}: {} {} -> {}", self.id, spec.name, self.current, self.desired)?;
+        if spec.is_eeprom() {
+            write!(f, " (EEPROM)")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("st3215-config-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn sample() -> BusConfig {
+        BusConfig {
+            servos: vec![
+                ServoConfig {
+                    id: 1,
+                    model: Some("STS3215".to_string()),
+                    registers: BTreeMap::from([
+                        ("max_temperature".to_string(), 70),
+                        ("position_offset".to_string(), -12),
+                    ]),
+                },
+                ServoConfig {
+                    id: 2,
+                    model: None,
+                    registers: BTreeMap::from([("cw_dead_zone".to_string(), 1)]),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn toml_and_json_files_round_trip() {
+        let dir = temp_dir("round-trip");
+        let config = sample();
+
+        let toml_path = dir.join("bus.toml");
+        config.save(&toml_path).unwrap();
+        let text = fs::read_to_string(&toml_path).unwrap();
+        assert!(text.contains("[[servo]]"));
+        assert!(text.contains("[servo.registers]"));
+        assert_eq!(BusConfig::load(&toml_path).unwrap(), config);
+
+        let json_path = dir.join("bus.JSON");
+        config.save(&json_path).unwrap();
+        let text = fs::read_to_string(&json_path).unwrap();
+        assert!(text.trim_start().starts_with('{'));
+        assert!(text.contains("\"servo\""));
+        assert_eq!(BusConfig::load(&json_path).unwrap(), config);
+        assert_eq!(config.servo(2).unwrap().registers["cw_dead_zone"], 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unknown_extension_uses_toml() {
+        let dir = temp_dir("extension");
+        let config = sample();
+
+        let path = dir.join("bus.cfg");
+        config.save(&path).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("[[servo]]"));
+        assert_eq!(BusConfig::load(&path).unwrap(), config);
+
+        // Du JSON dans un fichier sans extension `.json` est lu comme du TOML
+        fs::write(&path, config.to_json().unwrap()).unwrap();
+        assert!(BusConfig::load(&path).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,548 @@
+use crate::values::*;
+use std::str::FromStr;
+
+/// Zone mémoire d'un registre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Memory {
+    /// Persistante, écriture après déverrouillage (`STS_LOCK`)
+    Eeprom,
+    /// Volatile, perdue à l'extinction
+    Sram,
+}
+
+/// Droits d'accès à un registre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// Encodage d'une valeur dans un registre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Unsigned,
+    /// Signe-magnitude Feetech: le bit donné porte le signe, les bits inférieurs la valeur absolue
+    SignMagnitude(u8),
+}
+
+impl Encoding {
+    /// Convertir la valeur brute du registre en valeur signée
+    pub fn decode(&self, raw: u16) -> i32 {
+        match *self {
+            Encoding::Unsigned => raw as i32,
+            Encoding::SignMagnitude(bit) => {
+                let magnitude = (raw & ((1u16 << bit) - 1)) as i32;
+                if raw & (1 << bit) != 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+        }
+    }
+
+    /// Convertir une valeur signée en valeur brute du registre
+    pub fn encode(&self, value: i32) -> u16 {
+        match *self {
+            Encoding::Unsigned => value as u16,
+            Encoding::SignMagnitude(bit) => {
+                let magnitude = (value.unsigned_abs() as u16) & ((1u16 << bit) - 1);
+                if value < 0 {
+                    magnitude | (1 << bit)
+                } else {
+                    magnitude
+                }
+            }
+        }
+    }
+}
+
+/// Description d'un registre de la table de contrôle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisterSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub address: u8,
+    /// Taille en octets (1 ou 2)
+    pub width: u8,
+    pub memory: Memory,
+    pub access: Access,
+    pub encoding: Encoding,
+    /// Valeur physique d'une unité du registre
+    pub scale: f32,
+    pub unit: &'static str,
+    /// Plage valide, en unités du registre (après décodage du signe)
+    pub min: i32,
+    pub max: i32,
+}
+
+impl RegisterSpec {
+    pub fn is_writable(&self) -> bool {
+        self.access == Access::ReadWrite
+    }
+
+    pub fn is_eeprom(&self) -> bool {
+        self.memory == Memory::Eeprom
+    }
+
+    /// Vérifier qu'une valeur est dans la plage du registre
+    pub fn validate(&self, value: i32) -> Result<(), String> {
+        if value < self.min || value > self.max {
+            return Err(format!(
+                "{} must be between {} and {} (got {})",
+                self.name, self.min, self.max, value
+            ));
+        }
+        Ok(())
+    }
+
+    /// Convertir une valeur du registre en unité physique
+    pub fn to_physical(&self, value: i32) -> f32 {
+        value as f32 * self.scale
+    }
+
+    /// Convertir une valeur physique en unités du registre (arrondie)
+    pub fn from_physical(&self, value: f32) -> i32 {
+        (value / self.scale).round() as i32
+    }
+}
+
+/// Registres de la table de contrôle STS3215
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Register {
+    FirmwareMajor,
+    FirmwareMinor,
+    Model,
+    Id,
+    BaudRate,
+    ReturnDelay,
+    ResponseLevel,
+    MinAngleLimit,
+    MaxAngleLimit,
+    MaxTemperature,
+    MaxVoltage,
+    MinVoltage,
+    MaxTorque,
+    Phase,
+    UnloadingCondition,
+    LedAlarm,
+    PCoefficient,
+    DCoefficient,
+    ICoefficient,
+    MinStartupForce,
+    CwDeadZone,
+    CcwDeadZone,
+    ProtectionCurrent,
+    AngularResolution,
+    PositionOffset,
+    Mode,
+    ProtectiveTorque,
+    ProtectionTime,
+    OverloadTorque,
+    SpeedPCoefficient,
+    OvercurrentProtectionTime,
+    SpeedICoefficient,
+    TorqueEnable,
+    Acceleration,
+    GoalPosition,
+    GoalTime,
+    GoalSpeed,
+    TorqueLimit,
+    Lock,
+    PresentPosition,
+    PresentSpeed,
+    PresentLoad,
+    PresentVoltage,
+    PresentTemperature,
+    AsyncWriteFlag,
+    Status,
+    Moving,
+    PresentCurrent,
+}
+
+impl Register {
+    /// Tous les registres, par adresse croissante
+    pub const ALL: &'static [Register] = &[
+        Register::FirmwareMajor,
+        Register::FirmwareMinor,
+        Register::Model,
+        Register::Id,
+        Register::BaudRate,
+        Register::ReturnDelay,
+        Register::ResponseLevel,
+        Register::MinAngleLimit,
+        Register::MaxAngleLimit,
+        Register::MaxTemperature,
+        Register::MaxVoltage,
+        Register::MinVoltage,
+        Register::MaxTorque,
+        Register::Phase,
+        Register::UnloadingCondition,
+        Register::LedAlarm,
+        Register::PCoefficient,
+        Register::DCoefficient,
+        Register::ICoefficient,
+        Register::MinStartupForce,
+        Register::CwDeadZone,
+        Register::CcwDeadZone,
+        Register::ProtectionCurrent,
+        Register::AngularResolution,
+        Register::PositionOffset,
+        Register::Mode,
+        Register::ProtectiveTorque,
+        Register::ProtectionTime,
+        Register::OverloadTorque,
+        Register::SpeedPCoefficient,
+        Register::OvercurrentProtectionTime,
+        Register::SpeedICoefficient,
+        Register::TorqueEnable,
+        Register::Acceleration,
+        Register::GoalPosition,
+        Register::GoalTime,
+        Register::GoalSpeed,
+        Register::TorqueLimit,
+        Register::Lock,
+        Register::PresentPosition,
+        Register::PresentSpeed,
+        Register::PresentLoad,
+        Register::PresentVoltage,
+        Register::PresentTemperature,
+        Register::AsyncWriteFlag,
+        Register::Status,
+        Register::Moving,
+        Register::PresentCurrent,
+    ];
+
+    /// Description complète du registre
+    pub fn spec(&self) -> RegisterSpec {
+        use Access::*;
+        use Encoding::*;
+        use Memory::*;
+
+        let (name, description, address, width, memory, access, encoding, scale, unit, min, max) = match self {
+            Register::FirmwareMajor => (
+                "firmware_major", "Version majeure du firmware",
+                STS_FIRMWARE_MAJOR, 1, Eeprom, ReadOnly, Unsigned, 1.0, "", 0, 255,
+            ),
+            Register::FirmwareMinor => (
+                "firmware_minor", "Version mineure du firmware",
+                STS_FIRMWARE_MINOR, 1, Eeprom, ReadOnly, Unsigned, 1.0, "", 0, 255,
+            ),
+            Register::Model => (
+                "model", "Numéro de modèle",
+                STS_MODEL_L, 2, Eeprom, ReadOnly, Unsigned, 1.0, "", 0, 65535,
+            ),
+            Register::Id => (
+                "id", "Identifiant du servo",
+                STS_ID, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 253,
+            ),
+            Register::BaudRate => (
+                "baud_rate", "Vitesse du bus (STS_1M à STS_38400)",
+                STS_BAUD_RATE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 7,
+            ),
+            Register::ReturnDelay => (
+                "return_delay", "Délai avant réponse",
+                STS_RETURN_DELAY, 1, Eeprom, ReadWrite, Unsigned, 2.0, "us", 0, 254,
+            ),
+            Register::ResponseLevel => (
+                "response_level", "Niveau de réponse (0: lectures seulement, 1: toutes)",
+                STS_RESPONSE_LEVEL, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 1,
+            ),
+            Register::MinAngleLimit => (
+                "min_angle_limit", "Limite d'angle minimale",
+                STS_MIN_ANGLE_LIMIT_L, 2, Eeprom, ReadWrite, Unsigned, 1.0, "step", 0, 4095,
+            ),
+            Register::MaxAngleLimit => (
+                "max_angle_limit", "Limite d'angle maximale",
+                STS_MAX_ANGLE_LIMIT_L, 2, Eeprom, ReadWrite, Unsigned, 1.0, "step", 0, 4095,
+            ),
+            Register::MaxTemperature => (
+                "max_temperature", "Température maximale",
+                STS_MAX_TEMPERATURE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "°C", 0, 100,
+            ),
+            Register::MaxVoltage => (
+                "max_voltage", "Tension d'entrée maximale",
+                STS_MAX_VOLTAGE, 1, Eeprom, ReadWrite, Unsigned, 0.1, "V", 0, 254,
+            ),
+            Register::MinVoltage => (
+                "min_voltage", "Tension d'entrée minimale",
+                STS_MIN_VOLTAGE, 1, Eeprom, ReadWrite, Unsigned, 0.1, "V", 0, 254,
+            ),
+            Register::MaxTorque => (
+                "max_torque", "Couple maximal (valeur au démarrage de la limite de couple)",
+                STS_MAX_TORQUE_L, 2, Eeprom, ReadWrite, Unsigned, 0.1, "%", 0, 1000,
+            ),
+            Register::Phase => (
+                "phase", "Phase (bits de fonctions spéciales)",
+                STS_PHASE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::UnloadingCondition => (
+                "unloading_condition", "Défauts coupant le couple (masque de bits ERRBIT_*)",
+                STS_UNLOADING_CONDITION, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::LedAlarm => (
+                "led_alarm", "Défauts signalés par la LED (masque de bits ERRBIT_*)",
+                STS_LED_ALARM, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::PCoefficient => (
+                "p_coefficient", "Coefficient P de la boucle de position",
+                STS_P_COEF, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::DCoefficient => (
+                "d_coefficient", "Coefficient D de la boucle de position",
+                STS_D_COEF, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::ICoefficient => (
+                "i_coefficient", "Coefficient I de la boucle de position",
+                STS_I_COEF, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::MinStartupForce => (
+                "min_startup_force", "Force de démarrage minimale",
+                STS_MIN_STARTUP_FORCE_L, 2, Eeprom, ReadWrite, Unsigned, 0.1, "%", 0, 1000,
+            ),
+            Register::CwDeadZone => (
+                "cw_dead_zone", "Zone morte sens horaire",
+                STS_CW_DEAD, 1, Eeprom, ReadWrite, Unsigned, 1.0, "step", 0, 32,
+            ),
+            Register::CcwDeadZone => (
+                "ccw_dead_zone", "Zone morte sens anti-horaire",
+                STS_CCW_DEAD, 1, Eeprom, ReadWrite, Unsigned, 1.0, "step", 0, 32,
+            ),
+            Register::ProtectionCurrent => (
+                "protection_current", "Courant de protection",
+                STS_PROTECTION_CURRENT_L, 2, Eeprom, ReadWrite, Unsigned, 6.5, "mA", 0, 511,
+            ),
+            Register::AngularResolution => (
+                "angular_resolution", "Facteur de résolution angulaire",
+                STS_ANGULAR_RESOLUTION, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 1, 3,
+            ),
+            Register::PositionOffset => (
+                "position_offset", "Correction de position",
+                STS_OFS_L, 2, Eeprom, ReadWrite, SignMagnitude(11), 1.0, "step", -2047, 2047,
+            ),
+            Register::Mode => (
+                "mode", "Mode opérationnel",
+                STS_MODE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 3,
+            ),
+            Register::ProtectiveTorque => (
+                "protective_torque", "Couple appliqué après une protection de surcharge",
+                STS_PROTECTIVE_TORQUE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "%", 0, 100,
+            ),
+            Register::ProtectionTime => (
+                "protection_time", "Durée de surcharge avant protection",
+                STS_PROTECTION_TIME, 1, Eeprom, ReadWrite, Unsigned, 10.0, "ms", 0, 254,
+            ),
+            Register::OverloadTorque => (
+                "overload_torque", "Seuil de couple de surcharge",
+                STS_OVERLOAD_TORQUE, 1, Eeprom, ReadWrite, Unsigned, 1.0, "%", 0, 100,
+            ),
+            Register::SpeedPCoefficient => (
+                "speed_p_coefficient", "Coefficient P de la boucle de vitesse",
+                STS_SPEED_P_COEF, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 100,
+            ),
+            Register::OvercurrentProtectionTime => (
+                "overcurrent_protection_time", "Durée de surintensité avant protection",
+                STS_OVERCURRENT_PROTECTION_TIME, 1, Eeprom, ReadWrite, Unsigned, 10.0, "ms", 0, 254,
+            ),
+            Register::SpeedICoefficient => (
+                "speed_i_coefficient", "Coefficient I de la boucle de vitesse",
+                STS_SPEED_I_COEF, 1, Eeprom, ReadWrite, Unsigned, 1.0, "", 0, 254,
+            ),
+            Register::TorqueEnable => (
+                "torque_enable", "Activation du couple (0, 1, ou 128 pour définir le milieu)",
+                STS_TORQUE_ENABLE, 1, Sram, ReadWrite, Unsigned, 1.0, "", 0, 128,
+            ),
+            Register::Acceleration => (
+                "acceleration", "Accélération",
+                STS_ACC, 1, Sram, ReadWrite, Unsigned, 100.0, "step/s²", 0, 254,
+            ),
+            Register::GoalPosition => (
+                "goal_position", "Position cible",
+                STS_GOAL_POSITION_L, 2, Sram, ReadWrite, SignMagnitude(15), 1.0, "step", -30719, 30719,
+            ),
+            Register::GoalTime => (
+                "goal_time", "Durée du mouvement",
+                STS_GOAL_TIME_L, 2, Sram, ReadWrite, Unsigned, 1.0, "ms", 0, 65535,
+            ),
+            Register::GoalSpeed => (
+                "goal_speed", "Vitesse cible",
+                STS_GOAL_SPEED_L, 2, Sram, ReadWrite, SignMagnitude(15), 1.0, "step/s", -3400, 3400,
+            ),
+            Register::TorqueLimit => (
+                "torque_limit", "Limite de couple",
+                STS_TORQUE_LIMIT_L, 2, Sram, ReadWrite, Unsigned, 0.1, "%", 0, 1000,
+            ),
+            Register::Lock => (
+                "lock", "Verrouillage de l'EEPROM",
+                STS_LOCK, 1, Sram, ReadWrite, Unsigned, 1.0, "", 0, 1,
+            ),
+            Register::PresentPosition => (
+                "present_position", "Position actuelle",
+                STS_PRESENT_POSITION_L, 2, Sram, ReadOnly, SignMagnitude(15), 1.0, "step", -32767, 32767,
+            ),
+            Register::PresentSpeed => (
+                "present_speed", "Vitesse actuelle",
+                STS_PRESENT_SPEED_L, 2, Sram, ReadOnly, SignMagnitude(15), 1.0, "step/s", -32767, 32767,
+            ),
+            Register::PresentLoad => (
+                "present_load", "Charge actuelle",
+                STS_PRESENT_LOAD_L, 2, Sram, ReadOnly, SignMagnitude(10), 0.1, "%", -1000, 1000,
+            ),
+            Register::PresentVoltage => (
+                "present_voltage", "Tension actuelle",
+                STS_PRESENT_VOLTAGE, 1, Sram, ReadOnly, Unsigned, 0.1, "V", 0, 255,
+            ),
+            Register::PresentTemperature => (
+                "present_temperature", "Température actuelle",
+                STS_PRESENT_TEMPERATURE, 1, Sram, ReadOnly, Unsigned, 1.0, "°C", 0, 255,
+            ),
+            Register::AsyncWriteFlag => (
+                "async_write_flag", "Écriture asynchrone en attente (REG_WRITE)",
+                STS_ASYNC_WRITE_FLAG, 1, Sram, ReadOnly, Unsigned, 1.0, "", 0, 1,
+            ),
+            Register::Status => (
+                "status", "Statut des capteurs (masque de bits ERRBIT_*)",
+                STS_STATUS, 1, Sram, ReadOnly, Unsigned, 1.0, "", 0, 255,
+            ),
+            Register::Moving => (
+                "moving", "Servo en mouvement",
+                STS_MOVING, 1, Sram, ReadOnly, Unsigned, 1.0, "", 0, 1,
+            ),
+            Register::PresentCurrent => (
+                "present_current", "Courant actuel",
+                STS_PRESENT_CURRENT_L, 2, Sram, ReadOnly, SignMagnitude(15), 6.5, "mA", -32767, 32767,
+            ),
+        };
+
+        RegisterSpec {
+            name,
+            description,
+            address,
+            width,
+            memory,
+            access,
+            encoding,
+            scale,
+            unit,
+            min,
+            max,
+        }
+    }
+
+    /// Nom du registre (ex: "max_temperature")
+    pub fn name(&self) -> &'static str {
+        self.spec().name
+    }
+
+    /// Retrouver un registre par son nom
+    pub fn from_name(name: &str) -> Option<Register> {
+        Register::ALL.iter().copied().find(|register| register.name() == name)
+    }
+
+    /// Registre de configuration, à sauvegarder avec `dump_config`
+    /// (EEPROM modifiable, plus les réglages SRAM accélération et limite de couple; l'ID est à part)
+    pub fn is_config(&self) -> bool {
+        let spec = self.spec();
+        match self {
+            Register::Id => false,
+            Register::Acceleration | Register::TorqueLimit => true,
+            _ => spec.is_eeprom() && spec.is_writable(),
+        }
+    }
+
+    /// Le registre existe-t-il dans la table de contrôle de cette variante ?
+    pub fn is_supported(&self, protocol: ProtocolVariant) -> bool {
+        self.address(protocol).is_some()
+    }
+
+    /// Adresse du registre pour une variante de protocole
+    /// Retourne None si le registre n'existe pas dans la table de cette variante
+    ///
+    /// La table SCS ne reprend que les registres connus (`SCS_*`): les autres adresses STS
+    /// correspondent à d'autres registres sur SCS (48, la limite de couple STS, y est le verrou EEPROM)
+    pub fn address(&self, protocol: ProtocolVariant) -> Option<u8> {
+        if protocol == ProtocolVariant::Sts {
+            return Some(self.spec().address);
+        }
+
+        match self {
+            Register::Model => Some(SCS_VERSION_L),
+            Register::Id => Some(SCS_ID),
+            Register::BaudRate => Some(SCS_BAUD_RATE),
+            Register::ReturnDelay => Some(SCS_RETURN_DELAY),
+            Register::ResponseLevel => Some(SCS_RESPONSE_LEVEL),
+            Register::MinAngleLimit => Some(SCS_MIN_ANGLE_LIMIT_L),
+            Register::MaxAngleLimit => Some(SCS_MAX_ANGLE_LIMIT_L),
+            Register::CwDeadZone => Some(SCS_CW_DEAD),
+            Register::CcwDeadZone => Some(SCS_CCW_DEAD),
+            Register::TorqueEnable => Some(SCS_TORQUE_ENABLE),
+            Register::GoalPosition => Some(SCS_GOAL_POSITION_L),
+            Register::GoalTime => Some(SCS_GOAL_TIME_L),
+            Register::GoalSpeed => Some(SCS_GOAL_SPEED_L),
+            Register::Lock => Some(SCS_LOCK),
+            Register::PresentPosition => Some(SCS_PRESENT_POSITION_L),
+            Register::PresentSpeed => Some(SCS_PRESENT_SPEED_L),
+            Register::PresentLoad => Some(SCS_PRESENT_LOAD_L),
+            Register::PresentVoltage => Some(SCS_PRESENT_VOLTAGE),
+            Register::PresentTemperature => Some(SCS_PRESENT_TEMPERATURE),
+            Register::Moving => Some(SCS_MOVING),
+            Register::PresentCurrent => Some(SCS_PRESENT_CURRENT_L),
+            _ => None,
+        }
+    }
+
+    /// Retrouver un registre par son adresse de départ
+    pub fn from_address(address: u8) -> Option<Register> {
+        Register::ALL
+            .iter()
+            .copied()
+            .find(|register| register.spec().address == address)
+    }
+}
+
+impl FromStr for Register {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Register::from_name(name).ok_or_else(|| format!("Unknown register: {}", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_magnitude_round_trips_on_each_sign_bit() {
+        for bit in [15u8, 11, 10] {
+            let encoding = Encoding::SignMagnitude(bit);
+            let max = (1i32 << bit) - 1;
+            for value in [0, 1, -1, 100, -100, max, -max] {
+                assert_eq!(encoding.decode(encoding.encode(value)), value, "bit {} value {}", bit, value);
+            }
+        }
+    }
+
+    #[test]
+    fn sign_magnitude_raw_layout() {
+        assert_eq!(Encoding::SignMagnitude(11).encode(-5), 0x0805);
+        assert_eq!(Encoding::SignMagnitude(10).encode(-1000), 0x0400 | 1000);
+        assert_eq!(Encoding::SignMagnitude(15).decode(0x8000 | 2048), -2048);
+        assert_eq!(Encoding::SignMagnitude(10).decode(0x0400), 0);
+    }
+
+    #[test]
+    fn unsigned_is_passthrough() {
+        assert_eq!(Encoding::Unsigned.encode(4095), 4095);
+        assert_eq!(Encoding::Unsigned.decode(4095), 4095);
+    }
+
+    #[test]
+    fn scs_rejects_registers_moved_or_missing() {
+        assert_eq!(Register::TorqueLimit.address(ProtocolVariant::Sts), Some(STS_TORQUE_LIMIT_L));
+        assert_eq!(Register::TorqueLimit.address(ProtocolVariant::Scs), None);
+        assert_eq!(Register::Lock.address(ProtocolVariant::Scs), Some(SCS_LOCK));
+        assert!(!Register::Acceleration.is_supported(ProtocolVariant::Scs));
+        assert!(Register::GoalPosition.is_supported(ProtocolVariant::Scs));
+    }
+}
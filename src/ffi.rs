@@ -4,6 +4,7 @@
 //! compatible, permettant l'utilisation depuis C++ et d'autres langages.
 
 use crate::st3215::ST3215;
+use crate::values::{ProtocolVariant, BROADCAST_ID};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
@@ -52,6 +53,36 @@ pub extern "C" fn st3215_free(handle: *mut ST3215Handle) {
     }
 }
 
+/// Déclarer la variante de protocole d'un servo
+///
+/// # Arguments
+/// * `handle` - Handle ST3215
+/// * `servo_id` - ID du servo
+/// * `protocol` - 0 pour STS (little-endian), 1 pour SCS (big-endian)
+///
+/// # Retour
+/// 0 en cas de succès, -1 en cas d'erreur
+///
+/// # Safety
+/// `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[unsafe(no_mangle)]
+pub extern "C" fn st3215_set_protocol(handle: *mut ST3215Handle, servo_id: u8, protocol: i32) -> i32 {
+    if handle.is_null() {
+        return -1;
+    }
+
+    let protocol = match protocol {
+        0 => ProtocolVariant::Sts,
+        1 => ProtocolVariant::Scs,
+        _ => return -1,
+    };
+
+    let st = unsafe { &(*handle).inner };
+    st.set_protocol(servo_id, protocol);
+    0
+}
+
 /// Vérifier la présence d'un servo
 ///
 /// # Arguments
@@ -135,6 +166,54 @@ pub extern "C" fn st3215_move_to(
     }
 }
 
+/// Déplacer plusieurs servos simultanément (une seule trame SYNC_WRITE)
+///
+/// # Arguments
+/// * `handle` - Handle ST3215
+/// * `servo_ids` - Tableau des IDs des servos
+/// * `positions` - Tableau des positions cibles
+/// * `speeds` - Tableau des vitesses
+/// * `accelerations` - Tableau des accélérations
+/// * `count` - Nombre de servos (taille de chaque tableau)
+///
+/// # Retour
+/// 0 en cas de succès, -1 en cas d'erreur
+///
+/// # Safety
+/// `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré.
+/// Chaque tableau doit contenir au moins `count` éléments valides; les pointeurs doivent être
+/// non NULL même si `count` vaut 0.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[unsafe(no_mangle)]
+pub extern "C" fn st3215_move_many(
+    handle: *mut ST3215Handle,
+    servo_ids: *const u8,
+    positions: *const u16,
+    speeds: *const u16,
+    accelerations: *const u8,
+    count: usize,
+) -> i32 {
+    if handle.is_null() || servo_ids.is_null() || positions.is_null() || speeds.is_null() || accelerations.is_null() {
+        return -1;
+    }
+
+    let st = unsafe { &(*handle).inner };
+    let moves: Vec<(u8, u16, u16, u8)> = unsafe {
+        let ids = std::slice::from_raw_parts(servo_ids, count);
+        let positions = std::slice::from_raw_parts(positions, count);
+        let speeds = std::slice::from_raw_parts(speeds, count);
+        let accelerations = std::slice::from_raw_parts(accelerations, count);
+        (0..count)
+            .map(|i| (ids[i], positions[i], speeds[i], accelerations[i]))
+            .collect()
+    };
+
+    match st.move_many(&moves) {
+        Ok(_) => 0,
+        Err(_) => -1,
+    }
+}
+
 /// Lire la position actuelle d'un servo
 ///
 /// # Arguments
@@ -374,6 +453,62 @@ pub extern "C" fn st3215_enable_torque(
     }
 }
 
+/// Limiter le couple d'un servo
+///
+/// # Arguments
+/// * `handle` - Handle ST3215
+/// * `servo_id` - ID du servo (254 pour tous les servos)
+/// * `percent` - Couple maximal autorisé (0-100 %)
+///
+/// # Retour
+/// 0 en cas de succès, -1 en cas d'erreur
+///
+/// # Safety
+/// `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[unsafe(no_mangle)]
+pub extern "C" fn st3215_set_torque_limit(handle: *mut ST3215Handle, servo_id: u8, percent: f32) -> i32 {
+    if handle.is_null() {
+        return -1;
+    }
+
+    let st = unsafe { &(*handle).inner };
+    let result = if servo_id == BROADCAST_ID {
+        st.set_torque_limit_all(percent)
+    } else {
+        st.set_torque_limit(servo_id, percent)
+    };
+    match result {
+        Ok(_) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Arrêt d'urgence: couper le couple de tous les servos
+///
+/// # Arguments
+/// * `handle` - Handle ST3215
+///
+/// # Retour
+/// 0 en cas de succès, -1 en cas d'erreur
+///
+/// # Safety
+/// `handle` doit être NULL ou un pointeur retourné par `st3215_new` et pas encore libéré
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[unsafe(no_mangle)]
+pub extern "C" fn st3215_emergency_stop(handle: *mut ST3215Handle) -> i32 {
+    if handle.is_null() {
+        return -1;
+    }
+
+    let st = unsafe { &(*handle).inner };
+    if st.emergency_stop().is_success() {
+        0
+    } else {
+        -1
+    }
+}
+
 /// Obtenir la version de la bibliothèque
 ///
 /// # Retour
@@ -21,6 +21,12 @@ impl GroupSyncWrite {
         }
     }
 
+    /// Nombre maximal de servos dans une trame (limité par `TXPACKET_MAX_LEN`)
+    pub fn max_servos(&self) -> usize {
+        // En-tête (2), ID, longueur, instruction, adresse, taille des données, somme de contrôle
+        (TXPACKET_MAX_LEN - 8) / (1 + self.data_length)
+    }
+
     fn make_param(&mut self) {
         if self.data_dict.is_empty() {
             return;
@@ -87,3 +93,19 @@ impl GroupSyncWrite {
         ph.sync_write_tx_only(self.start_address, self.data_length as u8, &self.param)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_servos_keeps_frame_within_tx_limit() {
+        for data_length in [2, 6, 7] {
+            let group = GroupSyncWrite::new(STS_GOAL_POSITION_L, data_length);
+            let frame = 8 + group.max_servos() * (1 + data_length);
+            assert!(frame <= TXPACKET_MAX_LEN);
+            assert!(frame + 1 + data_length > TXPACKET_MAX_LEN);
+        }
+        assert_eq!(GroupSyncWrite::new(STS_ACC, 7).max_servos(), 30);
+    }
+}
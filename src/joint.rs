@@ -0,0 +1,219 @@
+use crate::st3215::ST3215;
+use crate::units::{Angle, AngularAcceleration, AngularVelocity};
+
+/// Articulation calibrée, pilotée en coordonnées articulaires
+///
+/// La calibration est gardée en mémoire côté hôte (rien n'est écrit en EEPROM):
+/// angle servo = zéro + sens × rapport × angle articulaire
+#[derive(Debug, Clone, PartialEq)]
+pub struct Joint {
+    pub id: u8,
+    /// Angle du servo correspondant à l'angle articulaire 0
+    pub zero_offset: Angle,
+    /// Inverser le sens de rotation (membres gauche/droite en miroir)
+    pub inverted: bool,
+    /// Tours de servo par tour d'articulation (strictement positif, le sens est donné par `inverted`)
+    pub gear_ratio: f32,
+    /// Limites logicielles, en coordonnées articulaires
+    pub min: Option<Angle>,
+    pub max: Option<Angle>,
+    /// Position de repos, en coordonnées articulaires
+    pub home: Angle,
+}
+
+impl Joint {
+    /// Articulation sans calibration: le zéro articulaire est la position 0 du servo
+    pub fn new(id: u8) -> Self {
+        Self {
+            id,
+            zero_offset: Angle::default(),
+            inverted: false,
+            gear_ratio: 1.0,
+            min: None,
+            max: None,
+            home: Angle::default(),
+        }
+    }
+
+    /// Même articulation sur le membre opposé: sens inversé, mêmes limites articulaires
+    /// Le zéro doit être recalibré pour le nouveau servo (`set_zero_here`)
+    pub fn mirrored(&self, id: u8) -> Self {
+        Self {
+            id,
+            inverted: !self.inverted,
+            ..self.clone()
+        }
+    }
+
+    fn direction(&self) -> f32 {
+        if self.inverted {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Vérifier le rapport de réduction (fini et strictement positif)
+    pub fn check_gear_ratio(&self) -> Result<f32, String> {
+        if !self.gear_ratio.is_finite() || self.gear_ratio <= 0.0 {
+            return Err(format!(
+                "Joint {}: gear ratio must be finite and positive (got {})",
+                self.id, self.gear_ratio
+            ));
+        }
+        Ok(self.gear_ratio)
+    }
+
+    /// Convertir un angle articulaire en angle du servo
+    pub fn to_servo(&self, angle: Angle) -> Angle {
+        self.zero_offset + Angle::from_radians(self.direction() * self.gear_ratio * angle.radians())
+    }
+
+    /// Convertir un angle du servo en angle articulaire
+    pub fn from_servo(&self, angle: Angle) -> Angle {
+        Angle::from_radians((angle - self.zero_offset).radians() * self.direction() / self.gear_ratio)
+    }
+
+    /// Vérifier qu'un angle articulaire respecte les limites logicielles (et le rapport de réduction)
+    pub fn check(&self, angle: Angle) -> Result<Angle, String> {
+        self.check_gear_ratio()?;
+        let below = self.min.is_some_and(|min| angle < min);
+        let above = self.max.is_some_and(|max| angle > max);
+        if below || above {
+            return Err(format!(
+                "Joint {}: angle {} outside of limits {}..{}",
+                self.id,
+                angle,
+                self.min.map(|min| min.to_string()).unwrap_or_default(),
+                self.max.map(|max| max.to_string()).unwrap_or_default()
+            ));
+        }
+        Ok(angle)
+    }
+
+    /// Déplacer l'articulation vers un angle articulaire
+    /// velocity, acceleration: en coordonnées articulaires
+    pub fn move_to(
+        &self,
+        controller: &ST3215,
+        angle: Angle,
+        velocity: AngularVelocity,
+        acceleration: AngularAcceleration,
+        wait: bool,
+    ) -> Result<(), String> {
+        let angle = self.check(angle)?;
+        let target = self.to_servo(angle);
+        let velocity = AngularVelocity::from_radians_per_sec(velocity.radians_per_sec().abs() * self.gear_ratio);
+        let acceleration =
+            AngularAcceleration::from_radians_per_sec2(acceleration.radians_per_sec2().abs() * self.gear_ratio);
+
+        controller
+            .try_move_to_angle(self.id, target, velocity, acceleration, wait)
+            .map(|_| ())
+            .map_err(|e| format!("Joint {}: {}", self.id, e))
+    }
+
+    /// Ramener l'articulation à sa position de repos
+    pub fn go_home(
+        &self,
+        controller: &ST3215,
+        velocity: AngularVelocity,
+        acceleration: AngularAcceleration,
+        wait: bool,
+    ) -> Result<(), String> {
+        self.move_to(controller, self.home, velocity, acceleration, wait)
+    }
+
+    /// Lire l'angle articulaire actuel
+    /// Retourne None si la lecture échoue ou si le rapport de réduction est invalide
+    pub fn position(&self, controller: &ST3215) -> Option<Angle> {
+        self.check_gear_ratio().ok()?;
+        controller.read_angle(self.id).map(|angle| self.from_servo(angle))
+    }
+
+    /// Lire la vitesse articulaire actuelle
+    /// Retourne None si la lecture échoue ou si le rapport de réduction est invalide
+    pub fn velocity(&self, controller: &ST3215) -> Option<AngularVelocity> {
+        self.check_gear_ratio().ok()?;
+        controller
+            .read_angular_velocity(self.id)
+            .map(|velocity| self.velocity_from_servo(velocity))
+    }
+
+    /// Convertir une vitesse du servo en vitesse articulaire
+    pub fn velocity_from_servo(&self, velocity: AngularVelocity) -> AngularVelocity {
+        AngularVelocity::from_radians_per_sec(velocity.radians_per_sec() * self.direction() / self.gear_ratio)
+    }
+
+    /// Définir la position actuelle du servo comme zéro articulaire
+    pub fn set_zero_here(&mut self, controller: &ST3215) -> Result<(), String> {
+        self.zero_offset = controller
+            .read_angle(self.id)
+            .ok_or_else(|| format!("Joint {}: could not read position", self.id))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Angle, b: Angle) -> bool {
+        (a.degrees() - b.degrees()).abs() < 1e-3
+    }
+
+    fn calibrated() -> Joint {
+        Joint {
+            zero_offset: Angle::from_degrees(180.0),
+            gear_ratio: 2.0,
+            min: Some(Angle::from_degrees(-45.0)),
+            max: Some(Angle::from_degrees(90.0)),
+            ..Joint::new(3)
+        }
+    }
+
+    #[test]
+    fn servo_and_joint_angles_round_trip() {
+        let joint = calibrated();
+        let angle = Angle::from_degrees(30.0);
+        assert!(close(joint.to_servo(angle), Angle::from_degrees(240.0)));
+        assert!(close(joint.from_servo(joint.to_servo(angle)), angle));
+
+        let mirrored = joint.mirrored(4);
+        assert_eq!(mirrored.id, 4);
+        assert!(mirrored.inverted);
+        assert_eq!((mirrored.min, mirrored.max), (joint.min, joint.max));
+        assert!(close(mirrored.to_servo(angle), Angle::from_degrees(120.0)));
+        assert!(close(mirrored.from_servo(mirrored.to_servo(angle)), angle));
+    }
+
+    #[test]
+    fn check_enforces_soft_limits() {
+        let joint = calibrated();
+        assert!(joint.check(Angle::from_degrees(90.0)).is_ok());
+        assert!(joint.check(Angle::from_degrees(-45.0)).is_ok());
+        assert!(joint.check(Angle::from_degrees(91.0)).is_err());
+        assert!(joint.check(Angle::from_degrees(-46.0)).is_err());
+        assert!(Joint::new(1).check(Angle::from_degrees(1000.0)).is_ok());
+    }
+
+    #[test]
+    fn velocity_is_scaled_by_ratio_and_direction() {
+        let joint = calibrated().mirrored(4);
+        let velocity = joint.velocity_from_servo(AngularVelocity::from_degrees_per_sec(60.0));
+        assert!((velocity.degrees_per_sec() + 30.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn invalid_gear_ratios_are_rejected() {
+        for gear_ratio in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let joint = Joint {
+                gear_ratio,
+                ..Joint::new(1)
+            };
+            assert!(joint.check_gear_ratio().is_err());
+            assert!(joint.check(Angle::default()).is_err());
+        }
+        assert_eq!(calibrated().check_gear_ratio(), Ok(2.0));
+    }
+}
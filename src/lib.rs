@@ -8,6 +8,20 @@ mod port_handler;
 mod protocol_packet_handler;
 mod group_sync_write;
 mod group_sync_read;
+mod bus_arbiter;
+mod retry;
+mod scan;
+mod models;
+mod motion;
+mod control_table;
+mod config;
+mod joint;
+mod protection;
+mod provisioning;
+mod trajectory;
+mod tuning;
+mod units;
+mod wear;
 mod st3215;
 pub mod ffi;
 
@@ -16,4 +30,20 @@ pub use port_handler::PortHandler;
 pub use protocol_packet_handler::ProtocolPacketHandler;
 pub use group_sync_write::GroupSyncWrite;
 pub use group_sync_read::GroupSyncRead;
-pub use st3215::ST3215;
+pub use bus_arbiter::{BusArbiter, BusGuard, Priority};
+pub use retry::{BusStats, RetryPolicy};
+pub use scan::{ScanOptions, ScanProgress};
+pub use models::{find_model, ModelSpec, ServoInfo, MODELS};
+pub use motion::{trapezoid_duration, trapezoid_speed, MoveTiming};
+pub use control_table::{Access, Encoding, Memory, Register, RegisterSpec};
+pub use config::{BusConfig, Plan, RegisterChange, ServoConfig};
+pub use joint::Joint;
+pub use protection::ProtectionSettings;
+pub use provisioning::{ProvisioningPlan, ServoLabel};
+pub use trajectory::{
+    ProfileKind, Trajectory, TrajectoryLimits, TrajectoryProgress, TrajectoryStatus, TrajectoryStream,
+};
+pub use tuning::{DeadbandOptions, DeadbandReport, PidGains, StepResponse, TuningOptions, TuningResult};
+pub use units::{Angle, AngularAcceleration, AngularVelocity};
+pub use wear::{BudgetAction, EepromWear, WearCounters};
+pub use st3215::{Pipeline, ST3215};
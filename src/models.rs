@@ -0,0 +1,156 @@
+use crate::values::*;
+
+/// Caractéristiques nominales d'un modèle de servo Feetech
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelSpec {
+    /// Numéro de modèle lu dans `STS_MODEL_L/H`
+    pub model_number: u16,
+    pub name: &'static str,
+    pub protocol: ProtocolVariant,
+    /// Position maximale (la plage va de 0 à `max_position`)
+    pub max_position: u16,
+    /// Course angulaire couverte par la plage de positions (en degrés)
+    pub angle_range: f32,
+    /// Vitesse maximale (en step/s)
+    pub max_speed: u16,
+    /// Correction de position maximale, en valeur absolue (0 si non supportée)
+    pub max_correction: u16,
+    /// Accélération maximale (unité: 100 step/s², 0 si non supportée)
+    pub max_acceleration: u8,
+    /// Plage de tension d'alimentation (en V)
+    pub min_voltage: f32,
+    pub max_voltage: f32,
+    /// Couple de blocage à la tension nominale (en kg·cm)
+    pub stall_torque: f32,
+}
+
+impl ModelSpec {
+    /// Caractéristiques utilisées pour un servo non identifié de cette variante de protocole
+    /// (STS3215 pour STS, SCS0009 pour SCS)
+    pub fn for_protocol(protocol: ProtocolVariant) -> &'static ModelSpec {
+        MODELS
+            .iter()
+            .find(|spec| spec.protocol == protocol)
+            .unwrap_or(&MODELS[0])
+    }
+
+    /// Nombre de steps par tour complet
+    pub fn steps_per_revolution(&self) -> f32 {
+        (self.max_position as f32 + 1.0) * 360.0 / self.angle_range
+    }
+
+    /// Convertir une position (en steps) en angle (en degrés)
+    pub fn steps_to_degrees(&self, steps: f32) -> f32 {
+        steps * 360.0 / self.steps_per_revolution()
+    }
+
+    /// Convertir un angle (en degrés) en position (en steps, arrondie)
+    pub fn degrees_to_steps(&self, degrees: f32) -> f32 {
+        (degrees * self.steps_per_revolution() / 360.0).round()
+    }
+
+    /// Vérifier qu'une position est dans la plage du modèle
+    pub fn validate_position(&self, position: u16) -> Result<(), String> {
+        if position > self.max_position {
+            return Err(format!(
+                "position must be between 0 and {} for {} (got {})",
+                self.max_position, self.name, position
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Table des modèles connus
+///
+/// Le STS3032 n'y figure pas: son numéro de modèle n'est pas confirmé. Un servo non reconnu est
+/// identifié comme "Unknown" et utilise les caractéristiques de sa variante de protocole
+/// (`ModelSpec::for_protocol`); ajouter l'entrée dès que le numéro est relevé sur un servo.
+pub const MODELS: &[ModelSpec] = &[
+    ModelSpec {
+        model_number: 777,
+        name: "STS3215",
+        protocol: ProtocolVariant::Sts,
+        max_position: MAX_POSITION,
+        angle_range: 360.0,
+        max_speed: MAX_SPEED,
+        max_correction: MAX_CORRECTION,
+        max_acceleration: 254,
+        min_voltage: 4.0,
+        max_voltage: 14.0,
+        stall_torque: 19.5,
+    },
+    ModelSpec {
+        model_number: 2825,
+        name: "STS3250",
+        protocol: ProtocolVariant::Sts,
+        max_position: MAX_POSITION,
+        angle_range: 360.0,
+        max_speed: MAX_SPEED,
+        max_correction: MAX_CORRECTION,
+        max_acceleration: 254,
+        min_voltage: 9.0,
+        max_voltage: 12.6,
+        stall_torque: 50.0,
+    },
+    ModelSpec {
+        model_number: 11272,
+        name: "SM8512BL",
+        protocol: ProtocolVariant::Sts,
+        max_position: MAX_POSITION,
+        angle_range: 360.0,
+        max_speed: MAX_SPEED,
+        max_correction: MAX_CORRECTION,
+        max_acceleration: 254,
+        min_voltage: 9.0,
+        max_voltage: 25.0,
+        stall_torque: 85.0,
+    },
+    ModelSpec {
+        model_number: 1284,
+        name: "SCS0009",
+        protocol: ProtocolVariant::Scs,
+        max_position: SCS_MAX_POSITION,
+        angle_range: 300.0,
+        max_speed: SCS_MAX_SPEED,
+        max_correction: 0,
+        max_acceleration: 0,
+        min_voltage: 4.0,
+        max_voltage: 7.4,
+        stall_torque: 2.3,
+    },
+];
+
+/// Rechercher un modèle par son numéro
+/// Le numéro est aussi essayé avec les octets inversés, pour identifier un servo
+/// interrogé avec la mauvaise variante de protocole
+pub fn find_model(model_number: u16) -> Option<&'static ModelSpec> {
+    MODELS
+        .iter()
+        .find(|spec| spec.model_number == model_number)
+        .or_else(|| MODELS.iter().find(|spec| spec.model_number == model_number.swap_bytes()))
+}
+
+/// Identification d'un servo présent sur le bus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServoInfo {
+    pub id: u8,
+    /// Numéro de modèle brut, tel que lu sur le servo
+    pub model_number: u16,
+    pub firmware_major: u8,
+    pub firmware_minor: u8,
+    /// Caractéristiques du modèle, si présent dans la table
+    pub spec: Option<&'static ModelSpec>,
+}
+
+impl ServoInfo {
+    /// Nom du modèle ("Unknown" s'il est absent de la table)
+    pub fn model_name(&self) -> &'static str {
+        self.spec.map(|spec| spec.name).unwrap_or("Unknown")
+    }
+
+    /// Version du firmware sous la forme "majeur.mineur"
+    pub fn firmware_version(&self) -> String {
+        format!("{}.{}", self.firmware_major, self.firmware_minor)
+    }
+}
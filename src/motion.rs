@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+/// Contrainte de durée d'un mouvement coordonné (`ST3215::move_coordinated`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveTiming {
+    /// Durée imposée du mouvement
+    Duration(Duration),
+    /// Mouvement le plus rapide possible, vitesse (en step/s) et accélération (en 100 step/s²) maximales
+    Limits { speed: u16, acc: u8 },
+}
+
+/// Durée d'un mouvement à profil trapézoïdal symétrique (accélération, palier, décélération)
+/// distance: en steps, speed: vitesse de palier en step/s, acc: en step/s²
+/// Si la distance est trop courte pour atteindre la vitesse de palier, le profil est triangulaire
+pub fn trapezoid_duration(distance: f64, speed: f64, acc: f64) -> f64 {
+    if distance <= 0.0 || speed <= 0.0 || acc <= 0.0 {
+        return 0.0;
+    }
+
+    if distance >= speed * speed / acc {
+        distance / speed + speed / acc
+    } else {
+        2.0 * (distance / acc).sqrt()
+    }
+}
+
+/// Vitesse de palier (en step/s) pour parcourir une distance en une durée donnée, à accélération fixée
+/// Retourne None si l'accélération est trop faible pour y parvenir
+pub fn trapezoid_speed(distance: f64, duration: f64, acc: f64) -> Option<f64> {
+    if distance <= 0.0 {
+        return Some(0.0);
+    }
+    if duration <= 0.0 || acc <= 0.0 {
+        return None;
+    }
+
+    // distance = v·T - v²/a  =>  v² - a·T·v + a·distance = 0 (plus petite racine)
+    // Une faible marge absorbe les erreurs d'arrondi à la limite du profil triangulaire
+    let discriminant = (acc * duration).powi(2) - 4.0 * acc * distance;
+    if discriminant < -1e-9 * (acc * duration).powi(2) {
+        return None;
+    }
+    Some((acc * duration - discriminant.max(0.0).sqrt()) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn duration_of_trapezoidal_and_triangular_profiles() {
+        // Palier atteint: 0,5 s d'accélération et de décélération, 2 s à 500 step/s
+        assert!(close(trapezoid_duration(1000.0, 500.0, 1000.0), 2.5));
+        // Distance trop courte: profil triangulaire
+        assert!(close(trapezoid_duration(100.0, 2400.0, 5000.0), 2.0 * 0.02f64.sqrt()));
+        assert_eq!(trapezoid_duration(0.0, 500.0, 1000.0), 0.0);
+        assert_eq!(trapezoid_duration(100.0, 0.0, 1000.0), 0.0);
+    }
+
+    #[test]
+    fn speed_is_the_inverse_of_duration() {
+        let duration = trapezoid_duration(1000.0, 500.0, 1000.0);
+        assert!(close(trapezoid_speed(1000.0, duration, 1000.0).unwrap(), 500.0));
+
+        // À la limite du profil triangulaire, la vitesse de pointe vaut a·T/2
+        let duration = trapezoid_duration(100.0, 2400.0, 5000.0);
+        let speed = trapezoid_speed(100.0, duration, 5000.0).unwrap();
+        assert!((speed - 5000.0 * duration / 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn speed_rejects_unreachable_durations() {
+        assert_eq!(trapezoid_speed(1000.0, 1.0, 1000.0), None);
+        assert_eq!(trapezoid_speed(1000.0, 0.0, 1000.0), None);
+        assert_eq!(trapezoid_speed(0.0, 0.0, 0.0), Some(0.0));
+    }
+}
@@ -8,8 +8,11 @@ pub struct PortHandler {
     baudrate: u32,
     packet_start_time: Instant,
     packet_timeout: Duration,
+    read_timeout: Duration,
     tx_time_per_byte: f64,
-    pub is_using: bool,
+    pending_replies: usize,
+    /// Octets reçus en mode pipeline, pas encore regroupés en paquets de statut complets
+    reply_buffer: Vec<u8>,
 }
 
 impl PortHandler {
@@ -20,8 +23,10 @@ impl PortHandler {
             baudrate: DEFAULT_BAUDRATE,
             packet_start_time: Instant::now(),
             packet_timeout: Duration::from_millis(100), //ms
+            read_timeout: Duration::from_millis(100),
             tx_time_per_byte: 0.0,
-            is_using: false,
+            pending_replies: 0,
+            reply_buffer: Vec::new(),
         }
     }
 
@@ -67,6 +72,52 @@ impl PortHandler {
         }
     }
 
+    pub fn baudrate(&self) -> u32 {
+        self.baudrate
+    }
+
+    /// Changer la vitesse du port (le port reste ouvert)
+    pub fn set_baudrate(&mut self, baudrate: u32) -> Result<(), String> {
+        if let Some(ref mut port) = self.port {
+            port.set_baud_rate(baudrate).map_err(|e| e.to_string())?;
+        }
+        self.baudrate = baudrate;
+        self.tx_time_per_byte = (1000.0 / self.baudrate as f64) * 10.0;
+        Ok(())
+    }
+
+    /// Temps de transmission d'un octet (en ms)
+    pub fn tx_time_per_byte(&self) -> f64 {
+        self.tx_time_per_byte
+    }
+
+    /// Nombre de réponses attendues à des écritures envoyées en mode pipeline
+    pub fn pending_replies(&self) -> usize {
+        self.pending_replies
+    }
+
+    pub fn add_pending_reply(&mut self) {
+        self.pending_replies += 1;
+    }
+
+    /// Comptabiliser des réponses du pipeline reçues
+    pub fn acknowledge_replies(&mut self, count: usize) {
+        self.pending_replies = self.pending_replies.saturating_sub(count);
+        if self.pending_replies == 0 {
+            self.reply_buffer.clear();
+        }
+    }
+
+    pub fn clear_pending_replies(&mut self) {
+        self.pending_replies = 0;
+        self.reply_buffer.clear();
+    }
+
+    /// Octets des réponses du pipeline en cours de réception
+    pub fn reply_buffer(&mut self) -> &mut Vec<u8> {
+        &mut self.reply_buffer
+    }
+
     pub fn set_packet_timeout(&mut self, packet_length: usize) {
         self.packet_start_time = Instant::now();
         let timeout_ms = (self.tx_time_per_byte * packet_length as f64)
@@ -75,6 +126,29 @@ impl PortHandler {
         self.packet_timeout = Duration::from_millis(timeout_ms as u64);
     }
 
+    /// Allonger le délai d'attente courant (ex: délai de retour du servo)
+    pub fn extend_packet_timeout(&mut self, extra: Duration) {
+        self.packet_timeout += extra;
+    }
+
+    pub fn set_packet_timeout_duration(&mut self, timeout: Duration) {
+        self.packet_start_time = Instant::now();
+        self.packet_timeout = timeout;
+    }
+
+    /// Délai maximal d'une lecture bloquante sur le port série
+    pub fn read_timeout(&self) -> Duration {
+        self.read_timeout
+    }
+
+    pub fn set_read_timeout(&mut self, timeout: Duration) -> Result<(), String> {
+        if let Some(ref mut port) = self.port {
+            port.set_timeout(timeout).map_err(|e| e.to_string())?;
+        }
+        self.read_timeout = timeout;
+        Ok(())
+    }
+
     pub fn set_packet_timeout_millis(&mut self, msec: u64) {
         self.packet_start_time = Instant::now();
         self.packet_timeout = Duration::from_millis(msec);
@@ -95,7 +169,7 @@ impl PortHandler {
 
     fn setup_port(&mut self) -> Result<(), String> {
         let mut port = serialport::new(&self.port_name, self.baudrate)
-            .timeout(Duration::from_millis(100))
+            .timeout(self.read_timeout)
             .open()
             .map_err(|e| format!("Could not open port {}: {}", self.port_name, e))?;
 
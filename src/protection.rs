@@ -0,0 +1,169 @@
+use crate::control_table::Register;
+
+/// Seuils de protection du servo (EEPROM), en unités des registres
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProtectionSettings {
+    /// Température maximale (°C)
+    pub max_temperature: u8,
+    /// Tension d'entrée minimale (unité: 0.1 V)
+    pub min_voltage: u8,
+    /// Tension d'entrée maximale (unité: 0.1 V)
+    pub max_voltage: u8,
+    /// Couple maximal (unité: 0.1 %)
+    pub max_torque: u16,
+    /// Seuil de couple de surcharge (%)
+    pub overload_torque: u8,
+    /// Couple appliqué après une protection de surcharge (%)
+    pub protective_torque: u8,
+    /// Courant de protection (unité: 6.5 mA)
+    pub protection_current: u16,
+    /// Durée de surcharge avant protection (unité: 10 ms)
+    pub protection_time: u8,
+    /// Durée de surintensité avant protection (unité: 10 ms)
+    pub overcurrent_protection_time: u8,
+    /// Défauts qui coupent le couple (masque de bits ERRBIT_*)
+    pub unloading_condition: u8,
+}
+
+impl ProtectionSettings {
+    /// Registres correspondant à chaque seuil
+    pub const REGISTERS: [Register; 10] = [
+        Register::MaxTemperature,
+        Register::MinVoltage,
+        Register::MaxVoltage,
+        Register::MaxTorque,
+        Register::OverloadTorque,
+        Register::ProtectiveTorque,
+        Register::ProtectionCurrent,
+        Register::ProtectionTime,
+        Register::OvercurrentProtectionTime,
+        Register::UnloadingCondition,
+    ];
+
+    /// Valeurs des registres, dans l'ordre de `REGISTERS`
+    pub fn to_registers(&self) -> [(Register, i32); 10] {
+        let values = [
+            self.max_temperature as i32,
+            self.min_voltage as i32,
+            self.max_voltage as i32,
+            self.max_torque as i32,
+            self.overload_torque as i32,
+            self.protective_torque as i32,
+            self.protection_current as i32,
+            self.protection_time as i32,
+            self.overcurrent_protection_time as i32,
+            self.unloading_condition as i32,
+        ];
+        std::array::from_fn(|i| (Self::REGISTERS[i], values[i]))
+    }
+
+    /// Construire les seuils à partir des valeurs lues, dans l'ordre de `REGISTERS`
+    pub fn from_registers(values: [i32; 10]) -> Self {
+        Self {
+            max_temperature: values[0] as u8,
+            min_voltage: values[1] as u8,
+            max_voltage: values[2] as u8,
+            max_torque: values[3] as u16,
+            overload_torque: values[4] as u8,
+            protective_torque: values[5] as u8,
+            protection_current: values[6] as u16,
+            protection_time: values[7] as u8,
+            overcurrent_protection_time: values[8] as u8,
+            unloading_condition: values[9] as u8,
+        }
+    }
+
+    /// Vérifier chaque seuil par rapport à la plage de son registre
+    pub fn validate(&self) -> Result<(), String> {
+        for (register, value) in self.to_registers() {
+            register.spec().validate(value)?;
+        }
+        if self.min_voltage > self.max_voltage {
+            return Err(format!(
+                "min_voltage must not exceed max_voltage (got {} > {})",
+                self.min_voltage, self.max_voltage
+            ));
+        }
+        Ok(())
+    }
+
+    /// Tension d'entrée minimale (en V)
+    pub fn min_voltage_volts(&self) -> f32 {
+        self.min_voltage as f32 * 0.1
+    }
+
+    /// Tension d'entrée maximale (en V)
+    pub fn max_voltage_volts(&self) -> f32 {
+        self.max_voltage as f32 * 0.1
+    }
+
+    /// Courant de protection (en mA)
+    pub fn protection_current_ma(&self) -> f32 {
+        self.protection_current as f32 * 6.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> ProtectionSettings {
+        ProtectionSettings {
+            max_temperature: 70,
+            min_voltage: 40,
+            max_voltage: 140,
+            max_torque: 1000,
+            overload_torque: 80,
+            protective_torque: 20,
+            protection_current: 500,
+            protection_time: 200,
+            overcurrent_protection_time: 200,
+            unloading_condition: 44,
+        }
+    }
+
+    #[test]
+    fn valid_settings_pass() {
+        assert_eq!(settings().validate(), Ok(()));
+        assert!(ProtectionSettings::default().validate().is_ok());
+    }
+
+    fn with(change: fn(&mut ProtectionSettings)) -> ProtectionSettings {
+        let mut settings = settings();
+        change(&mut settings);
+        settings
+    }
+
+    #[test]
+    fn register_ranges_are_enforced() {
+        let cases = [
+            with(|s| s.max_temperature = 101),
+            with(|s| s.max_torque = 1001),
+            with(|s| s.overload_torque = 101),
+            with(|s| s.protective_torque = 101),
+            with(|s| s.protection_current = 512),
+            with(|s| s.protection_time = 255),
+            with(|s| (s.min_voltage, s.max_voltage) = (255, 255)),
+        ];
+        for case in cases {
+            assert!(case.validate().is_err(), "{:?}", case);
+        }
+    }
+
+    #[test]
+    fn min_voltage_must_not_exceed_max_voltage() {
+        assert!(with(|s| (s.min_voltage, s.max_voltage) = (90, 90)).validate().is_ok());
+
+        let error = with(|s| (s.min_voltage, s.max_voltage) = (120, 90)).validate().unwrap_err();
+        assert!(error.contains("min_voltage"), "{}", error);
+    }
+
+    #[test]
+    fn registers_round_trip_with_units() {
+        let settings = settings();
+        let values = settings.to_registers().map(|(_, value)| value);
+        assert_eq!(ProtectionSettings::from_registers(values), settings);
+        assert!((settings.max_voltage_volts() - 14.0).abs() < 1e-4);
+        assert!((settings.protection_current_ma() - 3250.0).abs() < 1e-3);
+    }
+}
@@ -1,19 +1,49 @@
 use crate::port_handler::PortHandler;
 use crate::values::*;
+use std::thread;
+use std::time::Duration;
 
 pub struct ProtocolPacketHandler<'a> {
     port_handler: &'a mut PortHandler,
+    protocol: ProtocolVariant,
     sts_end: u8,
+    response_level: ResponseLevel,
+    return_delay: Duration,
+    packet_timeout: Option<Duration>,
 }
 
 impl<'a> ProtocolPacketHandler<'a> {
     pub fn new(port_handler: &'a mut PortHandler) -> Self {
+        Self::with_protocol(port_handler, ProtocolVariant::Sts)
+    }
+
+    /// Créer un gestionnaire pour une variante de protocole donnée (STS ou SCS)
+    pub fn with_protocol(port_handler: &'a mut PortHandler, protocol: ProtocolVariant) -> Self {
         Self {
             port_handler,
-            sts_end: 0,
+            protocol,
+            sts_end: protocol.end(),
+            response_level: ResponseLevel::All,
+            return_delay: Duration::from_micros(0),
+            packet_timeout: None,
         }
     }
 
+    /// Indiquer le niveau de réponse configuré sur le servo cible
+    /// En mode `ReadOnly`, les écritures n'attendent plus d'acquittement
+    pub fn set_response_level(&mut self, level: ResponseLevel) {
+        self.response_level = level;
+    }
+
+    /// Indiquer le délai de retour configuré sur le servo cible (ajouté aux délais d'attente)
+    pub fn set_return_delay(&mut self, delay: Duration) {
+        self.return_delay = delay;
+    }
+
+    pub fn protocol(&self) -> ProtocolVariant {
+        self.protocol
+    }
+
     // Fonctions utilitaires de manipulation de bytes
     pub fn sts_makeword(&self, a: u8, b: u8) -> u16 {
         if self.sts_end == 0 {
@@ -63,13 +93,7 @@ impl<'a> ProtocolPacketHandler<'a> {
     pub fn tx_packet(&mut self, txpacket: &mut Vec<u8>) -> CommResult {
         let total_packet_length = txpacket[PKT_LENGTH] as usize + 4;
 
-        if self.port_handler.is_using {
-            return CommResult::PortBusy;
-        }
-        self.port_handler.is_using = true;
-
         if total_packet_length > TXPACKET_MAX_LEN {
-            self.port_handler.is_using = false;
             return CommResult::TxError;
         }
 
@@ -88,10 +112,7 @@ impl<'a> ProtocolPacketHandler<'a> {
         let _ = self.port_handler.clear_port();
         match self.port_handler.write_port(&txpacket[..total_packet_length]) {
             Ok(written) if written == total_packet_length => CommResult::Success,
-            _ => {
-                self.port_handler.is_using = false;
-                CommResult::TxFail
-            }
+            _ => CommResult::TxFail,
         }
     }
 
@@ -136,7 +157,6 @@ impl<'a> ProtocolPacketHandler<'a> {
 
                         if rx_length < wait_length {
                             if self.port_handler.is_packet_timeout() {
-                                self.port_handler.is_using = false;
                                 return (
                                     rxpacket,
                                     if rx_length == 0 {
@@ -156,7 +176,6 @@ impl<'a> ProtocolPacketHandler<'a> {
                         }
                         checksum = !checksum;
 
-                        self.port_handler.is_using = false;
                         if rxpacket[wait_length - 1] == checksum {
                             return (rxpacket, CommResult::Success);
                         } else {
@@ -168,7 +187,6 @@ impl<'a> ProtocolPacketHandler<'a> {
                 }
             } else {
                 if self.port_handler.is_packet_timeout() {
-                    self.port_handler.is_using = false;
                     return (
                         rxpacket,
                         if rx_length == 0 {
@@ -181,29 +199,43 @@ impl<'a> ProtocolPacketHandler<'a> {
             }
         }
 
-        self.port_handler.is_using = false;
         (rxpacket, CommResult::RxFail)
     }
 
     // Transmission et réception
     pub fn tx_rx_packet(&mut self, txpacket: &mut Vec<u8>) -> (Option<Vec<u8>>, CommResult, u8) {
+        // Ne pas confondre une réponse en retard du pipeline avec celle attendue
+        if self.port_handler.pending_replies() > 0 && txpacket[PKT_ID] != BROADCAST_ID {
+            self.flush_pipeline();
+        }
+
         let result = self.tx_packet(txpacket);
         if !result.is_success() {
             return (None, result, 0);
         }
 
         if txpacket[PKT_ID] == BROADCAST_ID {
-            self.port_handler.is_using = false;
+            return (None, result, 0);
+        }
+
+        // Le servo ne renvoie pas d'acquittement pour les écritures
+        if self.response_level == ResponseLevel::ReadOnly
+            && txpacket[PKT_INSTRUCTION] != INST_READ
+            && txpacket[PKT_INSTRUCTION] != INST_PING
+        {
             return (None, result, 0);
         }
 
         // Définition du délai d'attente
-        if txpacket[PKT_INSTRUCTION] == INST_READ {
+        if let Some(timeout) = self.packet_timeout {
+            self.port_handler.set_packet_timeout_duration(timeout);
+        } else if txpacket[PKT_INSTRUCTION] == INST_READ {
             self.port_handler
                 .set_packet_timeout(txpacket[PKT_PARAMETER0 + 1] as usize + 6);
         } else {
             self.port_handler.set_packet_timeout(6);
         }
+        self.port_handler.extend_packet_timeout(self.return_delay);
 
         loop {
             let (rxpacket, rx_result) = self.rx_packet();
@@ -224,13 +256,33 @@ impl<'a> ProtocolPacketHandler<'a> {
             return (0, CommResult::NotAvailable, 0);
         }
 
-        let mut txpacket = vec![0u8; 6];
-        txpacket[PKT_ID] = sts_id;
-        txpacket[PKT_LENGTH] = 2;
-        txpacket[PKT_INSTRUCTION] = INST_PING;
+        let (result, error) = self.ping_tx_rx(sts_id);
 
-        let (_rxpacket, result, error) = self.tx_rx_packet(&mut txpacket);
+        self.read_model_after_ping(sts_id, result, error)
+    }
+
+    /// Ping avec un délai d'attente dédié, pour balayer rapidement le bus
+    /// Le délai ne s'applique qu'au ping; la lecture du modèle utilise les délais habituels
+    pub fn ping_with_timeout(&mut self, sts_id: u8, timeout: Duration) -> (u16, CommResult, u8) {
+        if sts_id >= BROADCAST_ID {
+            return (0, CommResult::NotAvailable, 0);
+        }
+
+        let read_timeout = self.port_handler.read_timeout();
+        if self.port_handler.set_read_timeout(timeout).is_err() {
+            return (0, CommResult::NotAvailable, 0);
+        }
+        self.packet_timeout = Some(timeout);
 
+        let (result, error) = self.ping_tx_rx(sts_id);
+
+        self.packet_timeout = None;
+        let _ = self.port_handler.set_read_timeout(read_timeout);
+
+        self.read_model_after_ping(sts_id, result, error)
+    }
+
+    fn read_model_after_ping(&mut self, sts_id: u8, result: CommResult, error: u8) -> (u16, CommResult, u8) {
         if result.is_success() {
             let (data, read_result, read_error) = self.read_tx_rx(sts_id, 3, 2);
             if read_result.is_success() && data.len() >= 2 {
@@ -242,6 +294,16 @@ impl<'a> ProtocolPacketHandler<'a> {
         (0, result, error)
     }
 
+    fn ping_tx_rx(&mut self, sts_id: u8) -> (CommResult, u8) {
+        let mut txpacket = vec![0u8; 6];
+        txpacket[PKT_ID] = sts_id;
+        txpacket[PKT_LENGTH] = 2;
+        txpacket[PKT_INSTRUCTION] = INST_PING;
+
+        let (_rxpacket, result, error) = self.tx_rx_packet(&mut txpacket);
+        (result, error)
+    }
+
     // Lecture
     pub fn read_tx_rx(&mut self, sts_id: u8, address: u8, length: u8) -> (Vec<u8>, CommResult, u8) {
         if sts_id >= BROADCAST_ID {
@@ -322,9 +384,7 @@ impl<'a> ProtocolPacketHandler<'a> {
 
         txpacket[PKT_PARAMETER0 + 1..PKT_PARAMETER0 + 1 + length].copy_from_slice(data);
 
-        let result = self.tx_packet(&mut txpacket);
-        self.port_handler.is_using = false;
-        result
+        self.tx_packet(&mut txpacket)
     }
 
     pub fn write_1byte_tx_only(&mut self, sts_id: u8, address: u8, data: u8) -> CommResult {
@@ -367,6 +427,82 @@ impl<'a> ProtocolPacketHandler<'a> {
         result
     }
 
+    // Écritures en pipeline
+    /// Envoyer une écriture sans attendre sa réponse
+    /// Les réponses éventuelles sont écartées au fil des envois et par `flush_pipeline`
+    pub fn write_pipelined(&mut self, sts_id: u8, address: u8, data: &[u8]) -> CommResult {
+        self.drain_rx();
+
+        let result = self.write_tx_only(sts_id, address, data);
+        if result.is_success() && sts_id != BROADCAST_ID && self.response_level == ResponseLevel::All {
+            self.port_handler.add_pending_reply();
+        }
+        result
+    }
+
+    pub fn write_2byte_pipelined(&mut self, sts_id: u8, address: u8, data: u16) -> CommResult {
+        let bytes = [self.sts_lobyte(data), self.sts_hibyte(data)];
+        self.write_pipelined(sts_id, address, &bytes)
+    }
+
+    /// Envoyer une trame SYNC_WRITE en pipeline (aucune réponse n'est émise par les servos)
+    pub fn sync_write_pipelined(&mut self, start_address: u8, data_length: u8, param: &[u8]) -> CommResult {
+        self.drain_rx();
+        self.sync_write_tx_only(start_address, data_length, param)
+    }
+
+    /// Écarter les octets déjà reçus sans attendre
+    /// Les réponses du pipeline reconnues parmi ces octets sont comptabilisées
+    /// Retourne le nombre d'octets écartés
+    pub fn drain_rx(&mut self) -> usize {
+        let mut drained = 0;
+        while let Ok(available) = self.port_handler.get_bytes_available() {
+            if available == 0 {
+                break;
+            }
+            match self.port_handler.read_port(available as usize) {
+                Ok(data) if !data.is_empty() => {
+                    drained += data.len();
+                    if self.port_handler.pending_replies() > 0 {
+                        let buffer = self.port_handler.reply_buffer();
+                        buffer.extend_from_slice(&data);
+                        let received = take_status_packets(buffer);
+                        self.port_handler.acknowledge_replies(received);
+                    }
+                }
+                _ => break,
+            }
+        }
+        drained
+    }
+
+    /// Barrière: attendre que toutes les réponses du pipeline soient reçues
+    /// Retourne `RxTimeout` si des réponses manquent à l'expiration du délai (latence de
+    /// l'adaptateur USB comprise); elles sont alors abandonnées
+    pub fn flush_pipeline(&mut self) -> CommResult {
+        let pending = self.port_handler.pending_replies();
+        if pending == 0 {
+            return CommResult::Success;
+        }
+
+        self.port_handler.set_packet_timeout(pending * 6);
+        self.port_handler.extend_packet_timeout(self.return_delay * pending as u32);
+
+        loop {
+            self.drain_rx();
+            if self.port_handler.pending_replies() == 0 {
+                return CommResult::Success;
+            }
+
+            if self.port_handler.is_packet_timeout() {
+                self.port_handler.clear_pending_replies();
+                return CommResult::RxTimeout;
+            }
+
+            thread::sleep(Duration::from_micros(100));
+        }
+    }
+
     // Sync Read
     pub fn sync_read_tx(
         &mut self,
@@ -374,6 +510,10 @@ impl<'a> ProtocolPacketHandler<'a> {
         data_length: u8,
         param: &[u8],
     ) -> CommResult {
+        if self.port_handler.pending_replies() > 0 {
+            self.flush_pipeline();
+        }
+
         let param_length = param.len();
         let mut txpacket = vec![0u8; param_length + 8];
 
@@ -402,11 +542,9 @@ impl<'a> ProtocolPacketHandler<'a> {
 
             let rx_length = rxpacket.len();
             if rx_length >= wait_length {
-                self.port_handler.is_using = false;
                 return (CommResult::Success, rxpacket);
             } else {
                 if self.port_handler.is_packet_timeout() {
-                    self.port_handler.is_using = false;
                     return (
                         if rx_length == 0 {
                             CommResult::RxTimeout
@@ -419,7 +557,91 @@ impl<'a> ProtocolPacketHandler<'a> {
             }
         }
 
-        self.port_handler.is_using = false;
         (CommResult::RxFail, rxpacket)
     }
 }
+
+/// Extraire les paquets de statut complets en tête du tampon
+/// Les octets qui précèdent un en-tête, et les paquets dont la somme de contrôle est invalide,
+/// sont écartés; un paquet incomplet reste dans le tampon
+/// Retourne le nombre de paquets extraits
+fn take_status_packets(buffer: &mut Vec<u8>) -> usize {
+    let mut count = 0;
+    loop {
+        match buffer.windows(2).position(|header| header == [0xFF, 0xFF]) {
+            Some(start) => {
+                buffer.drain(..start);
+            }
+            None => {
+                // Conserver un éventuel premier octet d'en-tête
+                let keep = usize::from(buffer.last() == Some(&0xFF));
+                buffer.drain(..buffer.len() - keep);
+                return count;
+            }
+        }
+
+        if buffer.len() < 4 {
+            return count;
+        }
+        let length = buffer[PKT_LENGTH] as usize;
+        if buffer[PKT_ID] == 0xFF || length < 2 {
+            buffer.drain(..1);
+            continue;
+        }
+        let total = length + 4;
+        if buffer.len() < total {
+            return count;
+        }
+
+        let checksum = !buffer[PKT_ID..total - 1].iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+        if buffer[total - 1] == checksum {
+            buffer.drain(..total);
+            count += 1;
+        } else {
+            buffer.drain(..1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Réponse de statut à une écriture: FF FF ID 02 ERREUR SOMME
+    fn status(sts_id: u8, error: u8) -> Vec<u8> {
+        let checksum = !(sts_id.wrapping_add(2).wrapping_add(error));
+        vec![0xFF, 0xFF, sts_id, 2, error, checksum]
+    }
+
+    #[test]
+    fn counts_complete_status_packets() {
+        let mut buffer = [status(1, 0), status(2, 0), status(3, 0x20)].concat();
+        assert_eq!(take_status_packets(&mut buffer), 3);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn keeps_partial_packet_until_complete() {
+        let reply = status(7, 0);
+        let mut buffer = reply[..4].to_vec();
+        assert_eq!(take_status_packets(&mut buffer), 0);
+        assert_eq!(buffer.len(), 4);
+
+        buffer.extend_from_slice(&reply[4..]);
+        assert_eq!(take_status_packets(&mut buffer), 1);
+        assert!(buffer.is_empty());
+
+        let mut buffer = vec![0x00, 0xFF];
+        assert_eq!(take_status_packets(&mut buffer), 0);
+        assert_eq!(buffer, vec![0xFF]);
+    }
+
+    #[test]
+    fn skips_noise_and_corrupt_packets() {
+        let mut corrupt = status(4, 0);
+        corrupt[5] ^= 0x55;
+        let mut buffer = [vec![0x12, 0x00], corrupt, vec![0xFF], status(5, 0)].concat();
+        assert_eq!(take_status_packets(&mut buffer), 1);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::config::is_json;
+use crate::values::STS_1M;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// Plan de mise en service des servos neufs (ID 1 à 1 Mbaud en sortie d'usine)
+/// Les champs absents laissent la valeur d'usine du registre inchangée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvisioningPlan {
+    /// ID des servos neufs
+    #[serde(default = "default_factory_id")]
+    pub factory_id: u8,
+    /// Vitesse du bus des servos neufs (valeur du registre, `STS_1M` par défaut)
+    #[serde(default = "default_factory_baud_rate")]
+    pub factory_baud_rate: u8,
+    /// IDs à attribuer, dans l'ordre
+    pub ids: Vec<u8>,
+    /// Vitesse du bus à configurer (valeur du registre, `STS_1M` à `STS_38400`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baud_rate: Option<u8>,
+    /// Correction de position (en steps)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position_offset: Option<i16>,
+    /// Limites d'angle (min, max), en steps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angle_limits: Option<(u16, u16)>,
+    /// Mode de fonctionnement (0 = position, 1 = vitesse, 2 = PWM, 3 = pas à pas)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u8>,
+}
+
+fn default_factory_id() -> u8 {
+    1
+}
+
+fn default_factory_baud_rate() -> u8 {
+    STS_1M
+}
+
+impl Default for ProvisioningPlan {
+    fn default() -> Self {
+        Self {
+            factory_id: default_factory_id(),
+            factory_baud_rate: default_factory_baud_rate(),
+            ids: Vec::new(),
+            baud_rate: None,
+            position_offset: None,
+            angle_limits: None,
+            mode: None,
+        }
+    }
+}
+
+impl ProvisioningPlan {
+    /// Prochain ID du plan qui n'a pas encore été attribué
+    pub fn next_id(&self, labels: &[ServoLabel]) -> Option<u8> {
+        self.ids
+            .iter()
+            .copied()
+            .find(|id| !labels.iter().any(|label| label.id == *id))
+    }
+
+    /// Charger un plan depuis un fichier (`.json` ou TOML)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        if is_json(path) {
+            serde_json::from_str(&text).map_err(|e| format!("Invalid JSON provisioning plan: {}", e))
+        } else {
+            toml::from_str(&text).map_err(|e| format!("Invalid TOML provisioning plan: {}", e))
+        }
+    }
+}
+
+/// Étiquette d'un servo mis en service, relue sur le servo après configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServoLabel {
+    pub id: u8,
+    /// Nom du modèle ("Unknown" s'il est absent de la table)
+    pub model: String,
+    pub model_number: u16,
+    /// Version du firmware ("majeur.mineur")
+    pub firmware: String,
+    /// Vitesse du bus (valeur du registre)
+    pub baud_rate: u8,
+    pub position_offset: i16,
+    pub angle_limits: (u16, u16),
+    pub mode: u8,
+    /// Date de mise en service (secondes depuis l'epoch Unix)
+    pub provisioned_at: u64,
+}
+
+impl ServoLabel {
+    /// Ajouter l'étiquette à un fichier d'étiquettes (une ligne JSON par servo)
+    pub fn append_to(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let line = serde_json::to_string(self).map_err(|e| e.to_string())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    /// Lire les étiquettes d'un fichier (vide si le fichier n'existe pas)
+    pub fn load_all(path: impl AsRef<Path>) -> Result<Vec<Self>, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let text = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(|e| format!("Invalid label in {}: {}", path.display(), e)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(id: u8) -> ServoLabel {
+        ServoLabel {
+            id,
+            model: "STS3215".to_string(),
+            model_number: 777,
+            firmware: "3.10".to_string(),
+            baud_rate: 0,
+            position_offset: 0,
+            angle_limits: (0, 4095),
+            mode: 0,
+            provisioned_at: 0,
+        }
+    }
+
+    #[test]
+    fn next_id_skips_assigned_ids_in_plan_order() {
+        let plan: ProvisioningPlan = toml::from_str("ids = [3, 1, 2]").unwrap();
+        assert_eq!(plan.next_id(&[]), Some(3));
+        assert_eq!(plan.next_id(&[label(3)]), Some(1));
+        assert_eq!(plan.next_id(&[label(1), label(7), label(3)]), Some(2));
+        assert_eq!(plan.next_id(&[label(1), label(2), label(3)]), None);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::values::*;
+use std::time::Duration;
+
+/// Politique de réessai pour les erreurs transitoires du bus
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Nombre maximal de tentatives (1 = aucun réessai)
+    pub max_attempts: u32,
+    /// Délai avant le premier réessai
+    pub backoff: Duration,
+    /// Facteur multiplicatif appliqué au délai à chaque nouvelle tentative
+    pub backoff_factor: f64,
+    /// Résultats de communication considérés comme transitoires
+    pub retry_on: Vec<CommResult>,
+    /// Autoriser le réessai des écritures (à réserver aux écritures idempotentes)
+    pub retry_writes: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Aucun réessai: chaque échec est remonté immédiatement
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            backoff: Duration::from_millis(0),
+            backoff_factor: 1.0,
+            retry_on: Vec::new(),
+            retry_writes: false,
+        }
+    }
+
+    /// Réessayer les timeouts et paquets corrompus, lectures et écritures comprises
+    /// max_attempts: nombre total de tentatives
+    pub fn transient(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: Duration::from_millis(2),
+            backoff_factor: 2.0,
+            retry_on: vec![CommResult::RxTimeout, CommResult::RxCorrupt, CommResult::RxFail],
+            retry_writes: true,
+        }
+    }
+
+    /// Le résultat doit-il être réessayé ?
+    pub fn should_retry(&self, result: CommResult, is_write: bool) -> bool {
+        if is_write && !self.retry_writes {
+            return false;
+        }
+        self.retry_on.contains(&result)
+    }
+
+    /// Délai à respecter après l'échec de la tentative `attempt` (à partir de 1)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = self.backoff_factor.max(1.0).powi(attempt.saturating_sub(1) as i32);
+        self.backoff.mul_f64(factor)
+    }
+}
+
+/// Statistiques de communication sur le bus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BusStats {
+    /// Nombre total de tentatives de transaction
+    pub transactions: u64,
+    /// Tentatives réussies
+    pub successes: u64,
+    /// Réessais effectués suite à une erreur transitoire
+    pub retries: u64,
+    /// Transactions abandonnées après épuisement des tentatives
+    pub failures: u64,
+    pub tx_failures: u64,
+    pub rx_timeouts: u64,
+    pub rx_corrupt: u64,
+}
+
+impl BusStats {
+    /// Comptabiliser le résultat d'une tentative
+    pub fn record(&mut self, result: CommResult) {
+        self.transactions += 1;
+        match result {
+            CommResult::Success => self.successes += 1,
+            CommResult::TxFail | CommResult::TxError => self.tx_failures += 1,
+            CommResult::RxTimeout => self.rx_timeouts += 1,
+            CommResult::RxCorrupt => self.rx_corrupt += 1,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_grows_geometrically_from_backoff() {
+        let policy = RetryPolicy::transient(4);
+        assert_eq!(policy.delay(1), Duration::from_millis(2));
+        assert_eq!(policy.delay(2), Duration::from_millis(4));
+        assert_eq!(policy.delay(3), Duration::from_millis(8));
+    }
+
+    #[test]
+    fn delay_ignores_shrinking_factor() {
+        let policy = RetryPolicy {
+            backoff: Duration::from_millis(5),
+            backoff_factor: 0.5,
+            ..RetryPolicy::none()
+        };
+        assert_eq!(policy.delay(3), Duration::from_millis(5));
+        assert_eq!(policy.delay(0), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn writes_are_retried_only_when_allowed() {
+        let policy = RetryPolicy {
+            retry_writes: false,
+            ..RetryPolicy::transient(3)
+        };
+        assert!(policy.should_retry(CommResult::RxTimeout, false));
+        assert!(!policy.should_retry(CommResult::RxTimeout, true));
+        assert!(!policy.should_retry(CommResult::TxFail, false));
+    }
+}
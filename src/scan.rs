@@ -0,0 +1,120 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Options du balayage rapide du bus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// IDs à interroger
+    pub ids: RangeInclusive<u8>,
+    /// Délai d'attente de la réponse à chaque ping
+    pub ping_timeout: Duration,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            ids: 0..=253,
+            ping_timeout: Duration::from_millis(3),
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Balayer une plage d'IDs avec le délai par défaut
+    pub fn range(ids: RangeInclusive<u8>) -> Self {
+        Self {
+            ids,
+            ..Self::default()
+        }
+    }
+}
+
+/// Avancement du balayage, transmis après chaque ID interrogé
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// ID qui vient d'être interrogé
+    pub id: u8,
+    /// Nombre d'IDs interrogés jusqu'ici
+    pub scanned: usize,
+    /// Nombre total d'IDs à interroger
+    pub total: usize,
+    /// Numéro de modèle si un servo a répondu
+    pub model_number: Option<u16>,
+}
+
+/// Interroger chaque ID des options et signaler l'avancement
+/// ping: numéro de modèle du servo qui a répondu, None sinon
+pub(crate) fn run_scan(
+    options: &ScanOptions,
+    mut ping: impl FnMut(u8) -> Option<u16>,
+    mut progress: impl FnMut(&ScanProgress),
+) -> Vec<(u8, u16)> {
+    let total = options.ids.clone().count();
+    let mut servos = Vec::new();
+
+    for (index, id) in options.ids.clone().enumerate() {
+        let model_number = ping(id);
+        if let Some(model) = model_number {
+            servos.push((id, model));
+        }
+
+        progress(&ScanProgress {
+            id,
+            scanned: index + 1,
+            total,
+            model_number,
+        });
+    }
+
+    servos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_scans_every_unicast_id() {
+        let options = ScanOptions::default();
+        assert_eq!(options.ids, 0..=253);
+        assert_eq!(options.ids.clone().count(), 254);
+
+        let options = ScanOptions::range(10..=20);
+        assert_eq!(options.ids, 10..=20);
+        assert_eq!(options.ping_timeout, ScanOptions::default().ping_timeout);
+    }
+
+    #[test]
+    fn progress_is_reported_for_every_id() {
+        let options = ScanOptions::range(3..=7);
+        let mut reports = Vec::new();
+        let servos = run_scan(
+            &options,
+            |id| match id {
+                4 => Some(777),
+                6 => Some(1284),
+                _ => None,
+            },
+            |progress| reports.push(*progress),
+        );
+
+        assert_eq!(servos, vec![(4, 777), (6, 1284)]);
+        assert_eq!(reports.len(), 5);
+        assert!(reports.iter().all(|report| report.total == 5));
+        assert_eq!(
+            reports.iter().map(|report| (report.id, report.scanned)).collect::<Vec<_>>(),
+            vec![(3, 1), (4, 2), (5, 3), (6, 4), (7, 5)]
+        );
+        assert_eq!(reports[1].model_number, Some(777));
+        assert_eq!(reports[2].model_number, None);
+    }
+
+    #[test]
+    fn empty_range_scans_nothing() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let options = ScanOptions::range(5..=4);
+        let mut calls = 0;
+        assert!(run_scan(&options, |_| Some(1), |_| calls += 1).is_empty());
+        assert_eq!(calls, 0);
+    }
+}
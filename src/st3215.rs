@@ -1,21 +1,164 @@
+use crate::bus_arbiter::{BusArbiter, BusGuard, Priority};
+use crate::config::{BusConfig, Plan, RegisterChange, ServoConfig};
+use crate::control_table::Register;
+use crate::group_sync_read::GroupSyncRead;
 use crate::group_sync_write::GroupSyncWrite;
+use crate::models::{find_model, ModelSpec, ServoInfo};
+use crate::motion::{trapezoid_duration, trapezoid_speed, MoveTiming};
 use crate::port_handler::PortHandler;
+use crate::protection::ProtectionSettings;
 use crate::protocol_packet_handler::ProtocolPacketHandler;
+use crate::provisioning::{ProvisioningPlan, ServoLabel};
 use crate::values::*;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use crate::retry::{BusStats, RetryPolicy};
+use crate::scan::{run_scan, ScanOptions, ScanProgress};
+use crate::wear::{BudgetAction, EepromWear};
+use crate::units::{Angle, AngularAcceleration, AngularVelocity};
+use crate::trajectory::{ProfileKind, Trajectory, TrajectoryLimits, TrajectoryStream};
+use crate::tuning::{DeadbandOptions, DeadbandReport, PidGains, StepResponse, TuningOptions, TuningResult};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+thread_local! {
+    /// Options surchargées pour les appels du thread courant
+    static CALL_OPTIONS: RefCell<CallOptions> = const {
+        RefCell::new(CallOptions {
+            retry_policy: None,
+            priority: None,
+            deadline: None,
+        })
+    };
+}
+
+/// Options d'un appel, définies par `with_retry_policy`, `with_priority` et `with_timeout`
+#[derive(Debug, Clone)]
+struct CallOptions {
+    retry_policy: Option<RetryPolicy>,
+    priority: Option<Priority>,
+    deadline: Option<Instant>,
+}
+
+/// État connu côté hôte pour un servo du bus
+#[derive(Debug, Clone, Copy)]
+struct ServoState {
+    protocol: ProtocolVariant,
+    response_level: ResponseLevel,
+    return_delay_us: u32,
+    model: Option<&'static ModelSpec>,
+    /// Limites d'angle (min, max) lues ou écrites sur le servo
+    angle_limits: Option<(u16, u16)>,
+    /// Le modèle a déjà été recherché (par `identify` ou `capabilities`)
+    probed: bool,
+}
+
+/// Accès exclusif au bus: réservation auprès de l'arbitre et verrou du port
+/// Les champs sont détruits dans l'ordre de déclaration: le port est déverrouillé avant que
+/// l'arbitre ne passe la main au suivant, qui n'a donc pas à attendre le verrou
+struct BusAccess<'a> {
+    port: MutexGuard<'a, PortHandler>,
+    _bus: BusGuard<'a>,
+}
+
+impl Deref for BusAccess<'_> {
+    type Target = PortHandler;
+
+    fn deref(&self) -> &PortHandler {
+        &self.port
+    }
+}
+
+impl DerefMut for BusAccess<'_> {
+    fn deref_mut(&mut self) -> &mut PortHandler {
+        &mut self.port
+    }
+}
+
+/// Lot d'écritures en pipeline (voir `ST3215::with_pipeline`)
+/// Chaque écriture est encodée selon l'état connu du servo cible: variante de protocole (ordre des
+/// octets), niveau de réponse (acquittement attendu ou non) et délai de retour
+pub struct Pipeline<'a> {
+    port: &'a mut PortHandler,
+    states: HashMap<u8, ServoState>,
+    default_state: ServoState,
+    /// Plus long délai de retour des servos adressés, attendu par `flush`
+    return_delay: Duration,
+}
 
+impl Pipeline<'_> {
+    fn handler(&mut self, sts_id: u8) -> ProtocolPacketHandler<'_> {
+        let state = self.states.get(&sts_id).copied().unwrap_or(self.default_state);
+        let delay = Duration::from_micros(state.return_delay_us as u64);
+        self.return_delay = self.return_delay.max(delay);
+
+        let mut handler = ProtocolPacketHandler::with_protocol(self.port, state.protocol);
+        handler.set_response_level(state.response_level);
+        handler.set_return_delay(delay);
+        handler
+    }
+
+    /// Envoyer une écriture sans attendre sa réponse
+    pub fn write(&mut self, sts_id: u8, address: u8, data: &[u8]) -> CommResult {
+        self.handler(sts_id).write_pipelined(sts_id, address, data)
+    }
+
+    pub fn write_1byte(&mut self, sts_id: u8, address: u8, data: u8) -> CommResult {
+        self.write(sts_id, address, &[data])
+    }
+
+    /// Envoyer une écriture de 2 octets, dans l'ordre des octets de la variante du servo
+    pub fn write_2byte(&mut self, sts_id: u8, address: u8, data: u16) -> CommResult {
+        self.handler(sts_id).write_2byte_pipelined(sts_id, address, data)
+    }
+
+    /// Envoyer une trame SYNC_WRITE (les paramètres doivent déjà être encodés pour chaque servo)
+    pub fn sync_write(&mut self, start_address: u8, data_length: u8, param: &[u8]) -> CommResult {
+        self.handler(BROADCAST_ID)
+            .sync_write_pipelined(start_address, data_length, param)
+    }
+
+    /// Attendre les réponses en attente et le silence du bus
+    fn flush(&mut self) -> CommResult {
+        let return_delay = self.return_delay;
+        let mut handler = self.handler(BROADCAST_ID);
+        handler.set_return_delay(return_delay);
+        handler.flush_pipeline()
+    }
+}
+
+/// Fonction appelée lorsqu'un registre EEPROM dépasse son budget d'écritures (ID, registre, nombre d'écritures)
+type BudgetCallback = Arc<dyn Fn(u8, Register, u64) + Send + Sync>;
+
+/// Les clones partagent le même port et le même état (utilisable depuis plusieurs threads)
+#[derive(Clone)]
 pub struct ST3215 {
     port_handler: Arc<Mutex<PortHandler>>,
-    #[allow(dead_code)]
     group_sync_write: Arc<Mutex<GroupSyncWrite>>,
+    default_protocol: ProtocolVariant,
+    servo_states: Arc<Mutex<HashMap<u8, ServoState>>>,
+    retry_policy: Arc<Mutex<RetryPolicy>>,
+    bus_stats: Arc<Mutex<BusStats>>,
+    arbiter: Arc<BusArbiter>,
+    limit_enforcement: Arc<Mutex<LimitEnforcement>>,
+    eeprom_wear: Arc<Mutex<EepromWear>>,
+    budget_callback: Arc<Mutex<Option<BudgetCallback>>>,
+    /// Dépassements du budget EEPROM non signalés (aucune fonction de rappel définie)
+    eeprom_warnings: Arc<Mutex<Vec<(u8, Register, u64)>>>,
 }
 
 impl ST3215 {
     /// Créer une nouvelle instance ST3215
     pub fn new(device: &str) -> Result<Self, String> {
+        Self::with_protocol(device, ProtocolVariant::Sts)
+    }
+
+    /// Créer une nouvelle instance avec une variante de protocole par défaut
+    /// protocol: variante utilisée pour les servos sans configuration explicite
+    pub fn with_protocol(device: &str, protocol: ProtocolVariant) -> Result<Self, String> {
         let mut port_handler = PortHandler::new(device);
         port_handler.open_port()?;
 
@@ -24,41 +167,880 @@ impl ST3215 {
         Ok(Self {
             port_handler: Arc::new(Mutex::new(port_handler)),
             group_sync_write: Arc::new(Mutex::new(group_sync_write)),
+            default_protocol: protocol,
+            servo_states: Arc::new(Mutex::new(HashMap::new())),
+            retry_policy: Arc::new(Mutex::new(RetryPolicy::default())),
+            bus_stats: Arc::new(Mutex::new(BusStats::default())),
+            arbiter: Arc::new(BusArbiter::new()),
+            limit_enforcement: Arc::new(Mutex::new(LimitEnforcement::default())),
+            eeprom_wear: Arc::new(Mutex::new(EepromWear::default())),
+            budget_callback: Arc::new(Mutex::new(None)),
+            eeprom_warnings: Arc::new(Mutex::new(Vec::new())),
         })
     }
 
+    /// Déclarer la variante de protocole d'un servo (bus mixtes STS/SCS)
+    pub fn set_protocol(&self, sts_id: u8, protocol: ProtocolVariant) {
+        self.update_state(sts_id, |state| state.protocol = protocol);
+    }
+
+    /// Obtenir la variante de protocole utilisée pour un servo
+    pub fn protocol(&self, sts_id: u8) -> ProtocolVariant {
+        self.servo_state(sts_id).protocol
+    }
+
+    fn servo_state(&self, sts_id: u8) -> ServoState {
+        let states = self.servo_states.lock().unwrap();
+        states.get(&sts_id).copied().unwrap_or_else(|| self.default_state())
+    }
+
+    fn update_state(&self, sts_id: u8, f: impl FnOnce(&mut ServoState)) {
+        let mut states = self.servo_states.lock().unwrap();
+        f(states.entry(sts_id).or_insert_with(|| self.default_state()));
+    }
+
+    fn default_state(&self) -> ServoState {
+        ServoState {
+            protocol: self.default_protocol,
+            response_level: ResponseLevel::All,
+            return_delay_us: 0,
+            model: None,
+            angle_limits: None,
+            probed: false,
+        }
+    }
+
+    fn packet_handler<'a>(&self, port: &'a mut PortHandler, sts_id: u8) -> ProtocolPacketHandler<'a> {
+        let state = self.servo_state(sts_id);
+        let mut handler = ProtocolPacketHandler::with_protocol(port, state.protocol);
+        handler.set_response_level(state.response_level);
+        handler.set_return_delay(Duration::from_micros(state.return_delay_us as u64));
+        handler
+    }
+
+    /// Obtenir le niveau de réponse connu pour un servo
+    pub fn response_level(&self, sts_id: u8) -> ResponseLevel {
+        self.servo_state(sts_id).response_level
+    }
+
+    /// Obtenir le délai de retour connu pour un servo (en µs)
+    pub fn return_delay(&self, sts_id: u8) -> u32 {
+        self.servo_state(sts_id).return_delay_us
+    }
+
+    /// Lire le niveau de réponse et le délai de retour du servo et les mémoriser
+    pub fn refresh_link_settings(&self, sts_id: u8) -> Result<(), String> {
+        let level = self
+            .read_response_level(sts_id)
+            .ok_or_else(|| format!("Could not read response level of servo {}", sts_id))?;
+        let delay = self
+            .read_return_delay(sts_id)
+            .ok_or_else(|| format!("Could not read return delay of servo {}", sts_id))?;
+
+        self.update_state(sts_id, |state| {
+            state.response_level = level;
+            state.return_delay_us = delay;
+        });
+        Ok(())
+    }
+
+    /// Lire le niveau de réponse configuré dans le servo
+    pub fn read_response_level(&self, sts_id: u8) -> Option<ResponseLevel> {
+        let (level, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_RESPONSE_LEVEL));
+        if comm.is_success() && error == 0 {
+            Some(ResponseLevel::from_u8(level))
+        } else {
+            None
+        }
+    }
+
+    /// Lire le délai de retour configuré dans le servo (en µs)
+    pub fn read_return_delay(&self, sts_id: u8) -> Option<u32> {
+        let (delay, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_RETURN_DELAY));
+        if comm.is_success() && error == 0 {
+            Some(delay as u32 * RETURN_DELAY_UNIT_US)
+        } else {
+            None
+        }
+    }
+
+    /// Configurer le niveau de réponse du servo (EEPROM)
+    /// level: `ReadOnly` pour ne plus recevoir d'acquittement des écritures
+    pub fn set_response_level(&self, sts_id: u8, level: ResponseLevel) -> Result<(), String> {
+        self.eeprom_transaction(sts_id, &[(Register::ResponseLevel, level as i32)])?;
+        Ok(())
+    }
+
+    /// Configurer le délai de retour du servo (EEPROM)
+    /// delay_us: délai en µs (0-508, par pas de 2 µs)
+    pub fn set_return_delay(&self, sts_id: u8, delay_us: u32) -> Result<(), String> {
+        if delay_us > MAX_RETURN_DELAY_US {
+            return Err(format!("delay_us must be between 0 and {}", MAX_RETURN_DELAY_US));
+        }
+
+        let value = (delay_us / RETURN_DELAY_UNIT_US) as i32;
+        self.eeprom_transaction(sts_id, &[(Register::ReturnDelay, value)])?;
+        Ok(())
+    }
+
+    /// Définir la politique de réessai par défaut de cette instance
+    pub fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self.retry_policy.lock().unwrap() = policy;
+    }
+
+    /// Obtenir la politique de réessai par défaut de cette instance
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy.lock().unwrap().clone()
+    }
+
+    /// Exécuter `f` avec une politique de réessai spécifique
+    /// La politique ne s'applique qu'aux appels faits depuis le thread courant pendant `f`
+    pub fn with_retry_policy<R>(&self, policy: RetryPolicy, f: impl FnOnce(&Self) -> R) -> R {
+        self.with_call_options(|options| options.retry_policy = Some(policy), f)
+    }
+
+    /// Exécuter `f` avec une priorité d'accès au bus spécifique (thread courant uniquement)
+    pub fn with_priority<R>(&self, priority: Priority, f: impl FnOnce(&Self) -> R) -> R {
+        self.with_call_options(|options| options.priority = Some(priority), f)
+    }
+
+    /// Exécuter `f` avec une échéance: les transactions qui n'obtiennent pas le bus
+    /// avant `timeout` échouent avec `CommResult::PortBusy` (thread courant uniquement)
+    pub fn with_timeout<R>(&self, timeout: Duration, f: impl FnOnce(&Self) -> R) -> R {
+        let deadline = Instant::now() + timeout;
+        self.with_call_options(|options| options.deadline = Some(deadline), f)
+    }
+
+    /// Arrêt d'urgence: couper le couple de tous les servos, avec la priorité maximale
+    pub fn emergency_stop(&self) -> CommResult {
+        let (comm, _) = self.with_priority(Priority::EmergencyStop, |st| {
+            st.write_with_retry(BROADCAST_ID, |handler| {
+                handler.write_tx_rx(BROADCAST_ID, STS_TORQUE_ENABLE, &[0])
+            })
+        });
+        comm
+    }
+
+    /// Exécuter des écritures en pipeline sur le bus, puis attendre qu'il soit silencieux
+    /// Le bus reste réservé pendant toute la durée de `f`; chaque écriture suit l'état connu
+    /// du servo cible (variante de protocole, niveau de réponse, délai de retour)
+    /// `f` ne doit appeler aucune autre méthode de `ST3215` accédant au bus: l'arbitre n'est pas
+    /// réentrant et l'appel bloquerait
+    ///
+    /// Retourne None si le bus n'a pas pu être obtenu avant l'échéance
+    ///
+    /// Exemple: `st.with_pipeline(|p| p.write_2byte(1, STS_GOAL_POSITION_L, 2048))`
+    pub fn with_pipeline<R>(&self, f: impl FnOnce(&mut Pipeline) -> R) -> (Option<R>, CommResult) {
+        let Some(mut bus) = self.acquire_bus(Priority::Control) else {
+            return (None, CommResult::PortBusy);
+        };
+        let mut pipeline = Pipeline {
+            port: &mut bus,
+            states: self.servo_states.lock().unwrap().clone(),
+            default_state: self.default_state(),
+            return_delay: Duration::ZERO,
+        };
+        let value = f(&mut pipeline);
+        let result = pipeline.flush();
+        (Some(value), result)
+    }
+
+    /// Obtenir les statistiques de communication du bus
+    pub fn bus_stats(&self) -> BusStats {
+        *self.bus_stats.lock().unwrap()
+    }
+
+    /// Remettre à zéro les statistiques de communication du bus
+    pub fn reset_bus_stats(&self) {
+        *self.bus_stats.lock().unwrap() = BusStats::default();
+    }
+
+    fn with_call_options<R>(&self, update: impl FnOnce(&mut CallOptions), f: impl FnOnce(&Self) -> R) -> R {
+        struct Restore(Option<CallOptions>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(previous) = self.0.take() {
+                    CALL_OPTIONS.with(|cell| *cell.borrow_mut() = previous);
+                }
+            }
+        }
+
+        let previous = CALL_OPTIONS.with(|cell| {
+            let mut options = cell.borrow_mut();
+            let previous = options.clone();
+            update(&mut options);
+            previous
+        });
+        let _restore = Restore(Some(previous));
+        f(self)
+    }
+
+    fn call_options() -> CallOptions {
+        CALL_OPTIONS.with(|cell| cell.borrow().clone())
+    }
+
+    /// Réserver le bus auprès de l'arbitre, puis verrouiller le port
+    /// Un port empoisonné par la panique d'un autre appelant reste utilisable
+    ///
+    /// L'arbitre n'est pas réentrant: un thread qui détient le bus ne doit appeler aucune autre
+    /// méthode accédant au bus (par exemple depuis la closure de `with_pipeline`), sous peine de blocage
+    fn acquire_bus(&self, default_priority: Priority) -> Option<BusAccess<'_>> {
+        let options = Self::call_options();
+        let bus = self
+            .arbiter
+            .acquire(options.priority.unwrap_or(default_priority), options.deadline)?;
+        let port = self.port_handler.lock().unwrap_or_else(PoisonError::into_inner);
+        Some(BusAccess { port, _bus: bus })
+    }
+
+    /// Exécuter une transaction sur le bus en appliquant la politique de réessai
+    fn transact<T: Default>(
+        &self,
+        sts_id: u8,
+        is_write: bool,
+        mut f: impl FnMut(&mut ProtocolPacketHandler) -> (T, CommResult, u8),
+    ) -> (T, CommResult, u8) {
+        let policy = Self::call_options()
+            .retry_policy
+            .unwrap_or_else(|| self.retry_policy());
+        let mut attempt = 1;
+
+        loop {
+            let (value, comm, error) = match self.acquire_bus(Priority::Control) {
+                Some(mut bus) => {
+                    let mut handler = self.packet_handler(&mut bus, sts_id);
+                    f(&mut handler)
+                }
+                None => (T::default(), CommResult::PortBusy, 0),
+            };
+
+            let mut stats = self.bus_stats.lock().unwrap();
+            stats.record(comm);
+
+            if comm.is_success() || attempt >= policy.max_attempts || !policy.should_retry(comm, is_write) {
+                if !comm.is_success() {
+                    stats.failures += 1;
+                }
+                return (value, comm, error);
+            }
+
+            stats.retries += 1;
+            drop(stats);
+
+            thread::sleep(policy.delay(attempt));
+            attempt += 1;
+        }
+    }
+
+    fn read_with_retry<T: Default>(
+        &self,
+        sts_id: u8,
+        f: impl FnMut(&mut ProtocolPacketHandler) -> (T, CommResult, u8),
+    ) -> (T, CommResult, u8) {
+        self.transact(sts_id, false, f)
+    }
+
+    fn write_with_retry(
+        &self,
+        sts_id: u8,
+        mut f: impl FnMut(&mut ProtocolPacketHandler) -> (CommResult, u8),
+    ) -> (CommResult, u8) {
+        let ((), comm, error) = self.transact(sts_id, true, |handler| {
+            let (comm, error) = f(handler);
+            ((), comm, error)
+        });
+        (comm, error)
+    }
+
     /// Vérifier la présence d'un servo
     pub fn ping_servo(&self, sts_id: u8) -> bool {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (model, comm, error) = handler.ping(sts_id);
+        let (model, comm, error) = self.read_with_retry(sts_id, |handler| handler.ping(sts_id));
         comm.is_success() && model != 0 && error == 0
     }
 
     /// Scanner le bus pour déterminer tous les servos présents
+    /// Chaque ID est interrogé avec le délai d'attente complet (adapté aux adaptateurs USB lents);
+    /// `scan` est plus rapide
     pub fn list_servos(&self) -> Vec<u8> {
         let mut servos = Vec::new();
-        // let mut servo_scanned = 0;
 
         for id in 0..254 {
-            // servo_scanned += 1;
-            // println!("Scan du servo {}/254 (ID {})", servo_scanned, id);
             if self.ping_servo(id) {
                 println!("Servo trouvé avec l'ID {}", id);
                 servos.push(id);
             }
         }
 
-        servos.sort();
-        println!("Scan terminé. {} servo(s) trouvé(s)", servos.len());
-        servos
+        servos.sort();
+        println!("Scan terminé. {} servo(s) trouvé(s)", servos.len());
+        servos
+    }
+
+    /// Balayer rapidement le bus avec un délai de ping court
+    /// progress: appelé après chaque ID interrogé
+    ///
+    /// Retourne les couples (ID, numéro de modèle) des servos ayant répondu, triés par ID
+    pub fn scan(&self, options: &ScanOptions, progress: impl FnMut(&ScanProgress)) -> Vec<(u8, u16)> {
+        run_scan(
+            options,
+            |id| {
+                let (model, comm, error) = match self.acquire_bus(Priority::Diagnostics) {
+                    Some(mut bus) => {
+                        let mut handler = self.packet_handler(&mut bus, id);
+                        handler.ping_with_timeout(id, options.ping_timeout)
+                    }
+                    None => (0, CommResult::PortBusy, 0),
+                };
+                (comm.is_success() && model != 0 && error == 0).then_some(model)
+            },
+            progress,
+        )
+    }
+
+    /// Identifier un servo: modèle, version du firmware et caractéristiques du modèle
+    /// Le modèle identifié est mémorisé, et la variante de protocole ajustée en conséquence
+    pub fn identify(&self, sts_id: u8) -> Result<ServoInfo, String> {
+        let (model_number, comm, error) = self.read_with_retry(sts_id, |handler| handler.ping(sts_id));
+        if !comm.is_success() || error != 0 || model_number == 0 {
+            return Err(format!("Could not find servo: {}", sts_id));
+        }
+
+        let spec = find_model(model_number);
+        self.update_state(sts_id, |state| {
+            state.probed = true;
+            if let Some(spec) = spec {
+                state.protocol = spec.protocol;
+                state.model = Some(spec);
+            }
+        });
+
+        let (firmware, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_tx_rx(sts_id, STS_FIRMWARE_MAJOR, 2));
+        if !comm.is_success() || error != 0 || firmware.len() < 2 {
+            return Err(format!("Could not read firmware version of servo {}", sts_id));
+        }
+
+        Ok(ServoInfo {
+            id: sts_id,
+            model_number,
+            firmware_major: firmware[0],
+            firmware_minor: firmware[1],
+            spec,
+        })
+    }
+
+    /// Obtenir le modèle mémorisé pour un servo (renseigné par `identify`)
+    pub fn model(&self, sts_id: u8) -> Option<&'static ModelSpec> {
+        self.servo_state(sts_id).model
+    }
+
+    /// Caractéristiques du modèle d'un servo (positions, vitesse, correction maximales)
+    /// Un servo inconnu ou absent reçoit les caractéristiques par défaut de sa variante de
+    /// protocole (`ModelSpec::for_protocol`)
+    ///
+    /// Tant que le servo n'a pas répondu à `identify`, chaque appel l'interroge sur le bus
+    /// (PING puis lecture du firmware, avec un délai d'attente si le servo est absent).
+    /// Appeler `identify` au démarrage évite ce trafic implicite.
+    pub fn capabilities(&self, sts_id: u8) -> &'static ModelSpec {
+        let state = self.servo_state(sts_id);
+        if let Some(spec) = state.model {
+            return spec;
+        }
+        if !state.probed && sts_id != BROADCAST_ID {
+            // `identify` ne marque le servo comme sondé que s'il a répondu
+            let _ = self.identify(sts_id);
+            if let Some(spec) = self.servo_state(sts_id).model {
+                return spec;
+            }
+        }
+        ModelSpec::for_protocol(self.protocol(sts_id))
+    }
+
+    /// Lire un registre de la table de contrôle
+    /// Retourne la valeur décodée (signe compris), en unités du registre
+    pub fn read_register(&self, sts_id: u8, register: Register) -> Result<i32, String> {
+        let spec = register.spec();
+        let Some(address) = register.address(self.protocol(sts_id)) else {
+            return Err(format!("Register {} is not available on servo {}", spec.name, sts_id));
+        };
+
+        let (raw, comm, error) = self.read_with_retry(sts_id, |handler| {
+            if spec.width == 2 {
+                handler.read_2byte_tx_rx(sts_id, address)
+            } else {
+                let (value, comm, error) = handler.read_1byte_tx_rx(sts_id, address);
+                (value as u16, comm, error)
+            }
+        });
+        if comm.is_success() && error == 0 {
+            Ok(spec.encoding.decode(raw))
+        } else {
+            Err(format!(
+                "Failed to read {} from servo {}: comm={:?}, error={}",
+                spec.name, sts_id, comm, error
+            ))
+        }
+    }
+
+    /// Lire un registre converti en unité physique (`RegisterSpec::unit`)
+    pub fn read_register_physical(&self, sts_id: u8, register: Register) -> Result<f32, String> {
+        let value = self.read_register(sts_id, register)?;
+        Ok(register.spec().to_physical(value))
+    }
+
+    /// Écrire un registre de la table de contrôle
+    /// value: valeur en unités du registre (signée pour les registres signe-magnitude)
+    ///
+    /// La plage est vérifiée avant l'écriture, et l'EEPROM déverrouillée puis reverrouillée
+    /// si nécessaire. L'ID se change avec `change_id`.
+    pub fn write_register(&self, sts_id: u8, register: Register, value: i32) -> Result<(), String> {
+        let spec = register.spec();
+        let protocol = self.protocol(sts_id);
+        if !spec.is_writable() {
+            return Err(format!("Register {} is read-only", spec.name));
+        }
+        if !register.is_supported(protocol) {
+            return Err(format!("Register {} is not available on servo {}", spec.name, sts_id));
+        }
+        spec.validate(value)?;
+
+        if spec.is_eeprom() {
+            return self.eeprom_transaction(sts_id, &[(register, value)]).map(|_| ());
+        }
+        self.write_register_raw(sts_id, register, value)
+    }
+
+    /// Écrire un ou plusieurs registres EEPROM de façon transactionnelle
+    ///
+    /// Les valeurs actuelles sont lues, l'EEPROM est déverrouillée, les registres modifiés sont
+    /// écrits puis l'EEPROM reverrouillée et chaque registre relu. En cas d'échec, les valeurs
+    /// d'origine sont réécrites. Les registres déjà à la bonne valeur ne sont pas réécrits.
+    ///
+    /// Un changement d'ID est appliqué après les autres registres, et le verrouillage et la
+    /// relecture utilisent le nouvel ID. Un changement de vitesse du bus est appliqué en dernier,
+    /// et le port de l'hôte passe à la nouvelle vitesse.
+    ///
+    /// Retourne l'ID du servo à l'issue de la transaction
+    pub fn eeprom_transaction(&self, sts_id: u8, writes: &[(Register, i32)]) -> Result<u8, String> {
+        let protocol = self.protocol(sts_id);
+        for &(register, value) in writes {
+            let spec = register.spec();
+            if !spec.is_eeprom() || !spec.is_writable() {
+                return Err(format!("Register {} is not a writable EEPROM register", spec.name));
+            }
+            if !register.is_supported(protocol) {
+                return Err(format!("Register {} is not available on servo {}", spec.name, sts_id));
+            }
+            spec.validate(value)?;
+            if register == Register::BaudRate && baud_rate_bps(value as u8).is_none() {
+                return Err(format!("Unknown baud rate code: {}", value));
+            }
+        }
+
+        // Ordre d'application: ID puis vitesse du bus en dernier
+        let mut pending = Vec::new();
+        for &(register, value) in writes {
+            let original = self.read_register(sts_id, register)?;
+            if original != value {
+                pending.push((register, value, original));
+            }
+        }
+        pending.sort_by_key(|(register, _, _)| match register {
+            Register::Id => 1,
+            Register::BaudRate => 2,
+            _ => 0,
+        });
+        if pending.is_empty() {
+            return Ok(sts_id);
+        }
+
+        {
+            let wear = self.eeprom_wear.lock().unwrap();
+            for (register, _, _) in &pending {
+                wear.check(sts_id, *register)?;
+            }
+            // Ne rien écrire si les compteurs ne peuvent pas être persistés
+            wear.save()
+                .map_err(|e| format!("EEPROM wear counters cannot be saved: {}", e))?;
+        }
+
+        if !self.unlock_eprom(sts_id).is_success() {
+            return Err("Could not unlock Eprom".to_string());
+        }
+
+        let mut current_id = sts_id;
+        let mut applied = Vec::new();
+        let mut failure = None;
+        let mut wear_errors = Vec::new();
+        for &(register, value, original) in &pending {
+            match self.write_eeprom_value(&mut current_id, register, value, &mut wear_errors) {
+                Ok(()) => applied.push((register, original)),
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            }
+        }
+        let _ = self.lock_eprom(current_id);
+
+        if failure.is_none() {
+            let mismatches: Vec<&str> = pending
+                .iter()
+                .filter(|(register, value, _)| self.read_register(current_id, *register).ok() != Some(*value))
+                .map(|(register, _, _)| register.name())
+                .collect();
+            if mismatches.is_empty() {
+                if !wear_errors.is_empty() {
+                    return Err(format!(
+                        "Changes applied on servo {}, but EEPROM wear counters could not be saved: {}",
+                        current_id,
+                        wear_errors.join(", ")
+                    ));
+                }
+                return Ok(current_id);
+            }
+            failure = Some(format!("Could not verify {} on servo {}", mismatches.join(", "), current_id));
+        }
+
+        // Retour arrière: réécrire les valeurs d'origine, dans l'ordre inverse
+        let failure = failure.unwrap_or_default();
+        if !self.unlock_eprom(current_id).is_success() {
+            return Err(format!("{} (rollback failed: could not unlock Eprom)", failure));
+        }
+        let rollback = applied
+            .iter()
+            .rev()
+            .try_for_each(|&(register, original)| {
+                self.write_eeprom_value(&mut current_id, register, original, &mut wear_errors)
+            });
+        let _ = self.lock_eprom(current_id);
+
+        match rollback {
+            Ok(()) => Err(format!("{} (changes rolled back)", failure)),
+            Err(e) => Err(format!("{} (rollback failed: {})", failure, e)),
+        }
+    }
+
+    /// Écrire un registre EEPROM et répercuter la modification côté hôte (ID, liaison, vitesse du bus)
+    /// Les erreurs d'écriture du fichier d'usure sont ajoutées à `wear_errors`: l'écriture sur le
+    /// servo a eu lieu, elle n'est pas annulée pour autant
+    fn write_eeprom_value(
+        &self,
+        sts_id: &mut u8,
+        register: Register,
+        value: i32,
+        wear_errors: &mut Vec<String>,
+    ) -> Result<(), String> {
+        self.write_register_raw(*sts_id, register, value)?;
+        if let Err(e) = self.record_eeprom_write(*sts_id, register) {
+            wear_errors.push(e);
+        }
+
+        match register {
+            Register::Id => {
+                let new_id = value as u8;
+                let mut states = self.servo_states.lock().unwrap();
+                if let Some(state) = states.remove(sts_id) {
+                    states.insert(new_id, state);
+                }
+                if let Err(e) = self.eeprom_wear.lock().unwrap().move_servo(*sts_id, new_id) {
+                    wear_errors.push(e);
+                }
+                *sts_id = new_id;
+            }
+            Register::ResponseLevel => {
+                self.update_state(*sts_id, |state| state.response_level = ResponseLevel::from_u8(value as u8));
+            }
+            Register::ReturnDelay => {
+                self.update_state(*sts_id, |state| state.return_delay_us = value as u32 * RETURN_DELAY_UNIT_US);
+            }
+            Register::BaudRate => {
+                let baudrate =
+                    baud_rate_bps(value as u8).ok_or_else(|| format!("Unknown baud rate code: {}", value))?;
+                // Laisser le servo appliquer la nouvelle vitesse avant de changer celle du port
+                thread::sleep(Duration::from_millis(10));
+                let mut bus = self
+                    .acquire_bus(Priority::Control)
+                    .ok_or_else(|| "Could not acquire the bus".to_string())?;
+                bus.set_baudrate(baudrate)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Comptabiliser une écriture EEPROM et signaler un dépassement du budget
+    /// Retourne l'erreur d'écriture du fichier d'état (le compteur en mémoire est à jour)
+    fn record_eeprom_write(&self, sts_id: u8, register: Register) -> Result<(), String> {
+        let mut wear = self.eeprom_wear.lock().unwrap();
+        let recorded = wear.record(sts_id, register);
+        let total = wear.count(sts_id, register);
+        let exceeded = wear.budget.is_some_and(|budget| total > budget);
+        drop(wear);
+
+        if exceeded {
+            self.report_budget_exceeded(sts_id, register, total);
+        }
+        recorded.map(|_| ())
+    }
+
+    fn report_budget_exceeded(&self, sts_id: u8, register: Register, count: u64) {
+        // Rappel appelé hors du verrou: il peut utiliser le contrôleur
+        let callback = self.budget_callback.lock().unwrap().clone();
+        match callback {
+            Some(callback) => callback(sts_id, register, count),
+            None => self.eeprom_warnings.lock().unwrap().push((sts_id, register, count)),
+        }
+    }
+
+    /// Suivre l'usure de l'EEPROM dans un fichier d'état JSON (créé s'il n'existe pas)
+    /// Les compteurs existants sont repris, et le fichier est mis à jour à chaque écriture EEPROM
+    pub fn track_eeprom_wear(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let mut loaded = EepromWear::load(path)?;
+        let mut wear = self.eeprom_wear.lock().unwrap();
+        loaded.budget = wear.budget;
+        loaded.action = wear.action;
+        *wear = loaded;
+        Ok(())
+    }
+
+    /// Définir le budget d'écritures EEPROM par servo et par registre
+    /// budget: nombre maximal d'écritures (None = illimité)
+    /// action: signaler (`Warn`) ou refuser (`Refuse`) les écritures au-delà du budget
+    pub fn set_eeprom_budget(&self, budget: Option<u64>, action: BudgetAction) {
+        let mut wear = self.eeprom_wear.lock().unwrap();
+        wear.budget = budget;
+        wear.action = action;
+    }
+
+    /// Être prévenu de chaque écriture EEPROM au-delà du budget (ID, registre, nombre d'écritures)
+    /// La fonction est appelée depuis le thread qui écrit, après l'écriture
+    pub fn on_eeprom_budget_exceeded(&self, callback: impl Fn(u8, Register, u64) + Send + Sync + 'static) {
+        *self.budget_callback.lock().unwrap() = Some(Arc::new(callback));
+    }
+
+    /// Récupérer les dépassements du budget EEPROM survenus sans fonction de rappel
+    /// (ID, registre, nombre d'écritures), dans l'ordre des écritures
+    pub fn take_eeprom_warnings(&self) -> Vec<(u8, Register, u64)> {
+        std::mem::take(&mut *self.eeprom_warnings.lock().unwrap())
+    }
+
+    /// Nombre d'écritures EEPROM comptabilisées pour un registre d'un servo
+    pub fn eeprom_writes(&self, sts_id: u8, register: Register) -> u64 {
+        self.eeprom_wear.lock().unwrap().count(sts_id, register)
+    }
+
+    /// État du suivi de l'usure de l'EEPROM (compteurs, budget, registres hors budget)
+    pub fn eeprom_wear(&self) -> EepromWear {
+        self.eeprom_wear.lock().unwrap().clone()
+    }
+
+    /// Écrire la valeur d'un registre, sans vérification ni gestion du verrou EEPROM
+    fn write_register_raw(&self, sts_id: u8, register: Register, value: i32) -> Result<(), String> {
+        let spec = register.spec();
+        let Some(address) = register.address(self.protocol(sts_id)) else {
+            return Err(format!("Register {} is not available on servo {}", spec.name, sts_id));
+        };
+        let raw = spec.encoding.encode(value);
+        // Pas d'acquittement fiable: le servo répond avec un autre ID, une autre vitesse,
+        // ou selon le niveau de réponse en cours de modification
+        let tx_only = matches!(register, Register::Id | Register::BaudRate | Register::ResponseLevel);
+        let (comm, error) = self.write_with_retry(sts_id, |handler| match (spec.width, tx_only) {
+            (2, _) => handler.write_2byte_tx_rx(sts_id, address, raw),
+            (_, true) => (handler.write_1byte_tx_only(sts_id, address, raw as u8), 0),
+            _ => handler.write_1byte_tx_rx(sts_id, address, raw as u8),
+        });
+
+        if matches!(register, Register::MinAngleLimit | Register::MaxAngleLimit) {
+            // Limites mémorisées pour check_position: relues au prochain contrôle
+            self.update_state(sts_id, |state| state.angle_limits = None);
+        }
+
+        if comm.is_success() && error == 0 {
+            Ok(())
+        } else {
+            Err(format!(
+                "Failed to write {} to servo {}: comm={:?}, error={}",
+                spec.name, sts_id, comm, error
+            ))
+        }
+    }
+
+    /// Écrire un registre à partir d'une valeur en unité physique (`RegisterSpec::unit`)
+    pub fn write_register_physical(&self, sts_id: u8, register: Register, value: f32) -> Result<(), String> {
+        let raw = register.spec().from_physical(value);
+        self.write_register(sts_id, register, raw)
+    }
+
+    /// Sauvegarder la configuration d'un servo (registres EEPROM et réglages SRAM)
+    pub fn dump_config(&self, sts_id: u8) -> Result<ServoConfig, String> {
+        let protocol = self.protocol(sts_id);
+        let mut registers = BTreeMap::new();
+        for register in Register::ALL.iter().filter(|register| register.is_config()) {
+            if register.is_supported(protocol) {
+                registers.insert(register.name().to_string(), self.read_register(sts_id, *register)?);
+            }
+        }
+
+        Ok(ServoConfig {
+            id: sts_id,
+            model: self.identify(sts_id).ok().map(|info| info.model_name().to_string()),
+            registers,
+        })
+    }
+
+    /// Sauvegarder la configuration de plusieurs servos (par exemple ceux de `list_servos`)
+    pub fn dump_bus(&self, ids: &[u8]) -> Result<BusConfig, String> {
+        let servos = ids
+            .iter()
+            .map(|&sts_id| self.dump_config(sts_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(BusConfig { servos })
+    }
+
+    /// Restaurer une configuration sur un servo, par exemple un servo de remplacement
+    /// sts_id: ID actuel du servo (changé en `config.id` si différent)
+    ///
+    /// Seuls les registres différents sont écrits (voir `apply`).
+    /// La vitesse du bus est restaurée en dernier, et le port de l'hôte passe à cette vitesse.
+    pub fn restore_config(&self, sts_id: u8, config: &ServoConfig) -> Result<(), String> {
+        let targets = Self::config_targets(config)?;
+
+        if sts_id != config.id {
+            self.change_id(sts_id, config.id)?;
+        }
+
+        let changes = self.plan_servo(config.id, &targets)?;
+        self.apply_servo(config.id, &changes)
+    }
+
+    /// Restaurer la configuration de tous les servos sauvegardés, chacun sur son ID
+    pub fn restore_bus(&self, config: &BusConfig) -> Result<(), String> {
+        for servo in &config.servos {
+            self.restore_config(servo.id, servo)?;
+        }
+        Ok(())
+    }
+
+    /// Comparer l'état souhaité à l'état actuel des servos, sans rien écrire
+    pub fn plan(&self, config: &BusConfig) -> Result<Plan, String> {
+        let mut changes = Vec::new();
+        for servo in &config.servos {
+            let targets = Self::config_targets(servo)?;
+            changes.extend(self.plan_servo(servo.id, &targets)?);
+        }
+        Ok(Plan { changes })
+    }
+
+    /// Appliquer un plan: seuls les registres modifiés sont écrits
+    ///
+    /// Pour chaque servo, les écritures EEPROM sont regroupées dans une seule fenêtre de
+    /// déverrouillage, puis chaque registre est relu pour vérification.
+    ///
+    /// Chaque modification est revérifiée avant toute écriture (registre de configuration,
+    /// présent sur le servo, valeur dans la plage): un plan construit à la main ou périmé
+    /// ne peut pas écrire un registre en lecture seule ou une valeur invalide.
+    pub fn apply(&self, plan: &Plan) -> Result<(), String> {
+        for change in &plan.changes {
+            let spec = change.register.spec();
+            if !change.register.is_config() {
+                return Err(format!("Register {} is not a configuration register", spec.name));
+            }
+            if !change.register.is_supported(self.protocol(change.id)) {
+                return Err(format!("Register {} is not available on servo {}", spec.name, change.id));
+            }
+            spec.validate(change.desired)?;
+        }
+
+        let mut ids: Vec<u8> = Vec::new();
+        for change in &plan.changes {
+            if !ids.contains(&change.id) {
+                ids.push(change.id);
+            }
+        }
+
+        for sts_id in ids {
+            let changes: Vec<RegisterChange> = plan
+                .changes
+                .iter()
+                .filter(|change| change.id == sts_id)
+                .copied()
+                .collect();
+            self.apply_servo(sts_id, &changes)?;
+        }
+        Ok(())
+    }
+
+    /// Registres d'une configuration, validés
+    fn config_targets(config: &ServoConfig) -> Result<Vec<(Register, i32)>, String> {
+        let targets = config
+            .registers
+            .iter()
+            .map(|(name, &value)| Ok((name.parse::<Register>()?, value)))
+            .collect::<Result<Vec<_>, String>>()?;
+        for (register, value) in &targets {
+            let spec = register.spec();
+            if !register.is_config() {
+                return Err(format!("Register {} is not a configuration register", spec.name));
+            }
+            spec.validate(*value)?;
+        }
+        Ok(targets)
+    }
+
+    /// Lire les registres d'un servo et retenir ceux qui diffèrent de la cible
+    fn plan_servo(&self, sts_id: u8, targets: &[(Register, i32)]) -> Result<Vec<RegisterChange>, String> {
+        let protocol = self.protocol(sts_id);
+        let mut changes = Vec::new();
+        for &(register, desired) in targets {
+            if !register.is_supported(protocol) {
+                continue;
+            }
+            let current = self.read_register(sts_id, register)?;
+            if current != desired {
+                changes.push(RegisterChange {
+                    id: sts_id,
+                    register,
+                    current,
+                    desired,
+                });
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Appliquer les modifications d'un servo
+    fn apply_servo(&self, sts_id: u8, changes: &[RegisterChange]) -> Result<(), String> {
+        let (eeprom, sram): (Vec<&RegisterChange>, Vec<&RegisterChange>) =
+            changes.iter().partition(|change| change.register.spec().is_eeprom());
+
+        for change in &sram {
+            self.write_register_raw(sts_id, change.register, change.desired)?;
+        }
+        let mismatches: Vec<&str> = sram
+            .iter()
+            .filter(|change| self.read_register(sts_id, change.register).ok() != Some(change.desired))
+            .map(|change| change.register.name())
+            .collect();
+        if !mismatches.is_empty() {
+            return Err(format!("Could not verify {} on servo {}", mismatches.join(", "), sts_id));
+        }
+
+        // En dernier: la transaction peut changer la vitesse du bus
+        let writes: Vec<(Register, i32)> = eeprom.iter().map(|change| (change.register, change.desired)).collect();
+        if !writes.is_empty() {
+            self.eeprom_transaction(sts_id, &writes)?;
+        }
+        Ok(())
     }
 
     /// Lire la charge du servo (en pourcentage)
     pub fn read_load(&self, sts_id: u8) -> Option<f32> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (load, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_PRESENT_LOAD_L);
+        let (load, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_PRESENT_LOAD_L));
         if comm.is_success() && error == 0 {
             Some(load as f32 * 0.1)
         } else {
@@ -68,9 +1050,8 @@ impl ST3215 {
 
     /// Lire la tension actuelle du servo (en V)
     pub fn read_voltage(&self, sts_id: u8) -> Option<f32> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (voltage, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_PRESENT_VOLTAGE);
+        let (voltage, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_PRESENT_VOLTAGE));
         if comm.is_success() && error == 0 {
             Some(voltage as f32 * 0.1)
         } else {
@@ -80,9 +1061,8 @@ impl ST3215 {
 
     /// Lire le courant actuel du servo (en mA)
     pub fn read_current(&self, sts_id: u8) -> Option<f32> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (current, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_PRESENT_CURRENT_L);
+        let (current, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_PRESENT_CURRENT_L));
         if comm.is_success() && error == 0 {
             Some(current as f32 * 6.5)
         } else {
@@ -90,11 +1070,22 @@ impl ST3215 {
         }
     }
 
+    /// Lire le courant actuel du servo (en A, signé selon le sens de rotation)
+    pub fn read_current_amps(&self, sts_id: u8) -> Option<f32> {
+        self.read_register_physical(sts_id, Register::PresentCurrent)
+            .ok()
+            .map(|current| current / 1000.0)
+    }
+
+    /// Lire la charge actuelle du servo (en % du couple maximal, signée selon le sens de rotation)
+    pub fn read_load_percent(&self, sts_id: u8) -> Option<f32> {
+        self.read_register_physical(sts_id, Register::PresentLoad).ok()
+    }
+
     /// Lire la température actuelle du servo (en °C)
     pub fn read_temperature(&self, sts_id: u8) -> Option<u8> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (temperature, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_PRESENT_TEMPERATURE);
+        let (temperature, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_PRESENT_TEMPERATURE));
         if comm.is_success() && error == 0 {
             Some(temperature)
         } else {
@@ -104,9 +1095,12 @@ impl ST3215 {
 
     /// Lire la valeur d'accélération actuelle du servo
     pub fn read_acceleration(&self, sts_id: u8) -> Option<u8> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (acc, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_ACC);
+        if !self.protocol(sts_id).has_acceleration() {
+            return None;
+        }
+
+        let (acc, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_ACC));
         if comm.is_success() && error == 0 {
             Some(acc)
         } else {
@@ -120,9 +1114,12 @@ impl ST3215 {
     /// - 2: Mode PWM
     /// - 3: Mode servo pas à pas
     pub fn read_mode(&self, sts_id: u8) -> Option<u8> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (mode, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_MODE);
+        if !self.protocol(sts_id).has_mode() {
+            return None;
+        }
+
+        let (mode, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_MODE));
         if comm.is_success() && error == 0 {
             Some(mode)
         } else {
@@ -132,28 +1129,15 @@ impl ST3215 {
 
     /// Lire la correction de position actuelle du servo
     pub fn read_correction(&self, sts_id: u8) -> Option<i16> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (correction, comm, error) = handler.read_2byte_tx_rx(sts_id, STS_OFS_L);
-        if comm.is_success() && error == 0 {
-            let mask = 0x07FFF;
-            let mut bits = correction & mask;
-            if (correction & 0x0800) != 0 {
-                bits = bits & 0x7FF;
-                Some(-(bits as i16))
-            } else {
-                Some(bits as i16)
-            }
-        } else {
-            None
-        }
+        self.read_register(sts_id, Register::PositionOffset)
+            .ok()
+            .map(|correction| correction as i16)
     }
 
     /// Le servo est-il en mouvement ?
     pub fn is_moving(&self, sts_id: u8) -> Option<bool> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (moving, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_MOVING);
+        let (moving, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_MOVING));
         if comm.is_success() && error == 0 {
             Some(moving != 0)
         } else {
@@ -164,9 +1148,13 @@ impl ST3215 {
     /// Configurer la valeur d'accélération pour le servo
     /// acc: Valeur d'accélération (0-254). Unité: 100 step/s²
     pub fn set_acceleration(&self, sts_id: u8, acc: u8) -> Option<bool> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_tx_rx(sts_id, STS_ACC, &[acc]);
+        if !self.protocol(sts_id).has_acceleration() {
+            return None;
+        }
+        let acc = acc.min(self.capabilities(sts_id).max_acceleration);
+
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_tx_rx(sts_id, STS_ACC, &[acc]));
         if comm.is_success() && error == 0 {
             Some(true)
         } else {
@@ -175,11 +1163,11 @@ impl ST3215 {
     }
 
     /// Configurer la valeur de vitesse pour le servo
-    /// speed: Valeur de vitesse (0-3400). Unité: Step/s
+    /// speed: Valeur de vitesse (0-3400 sur STS3215, limitée à la vitesse maximale du modèle). Unité: Step/s
     pub fn set_speed(&self, sts_id: u8, speed: u16) -> Option<bool> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_2byte_tx_rx(sts_id, STS_GOAL_SPEED_L, speed);
+        let speed = speed.min(self.capabilities(sts_id).max_speed);
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_2byte_tx_rx(sts_id, STS_GOAL_SPEED_L, speed));
         if comm.is_success() && error == 0 {
             Some(true)
         } else {
@@ -189,9 +1177,8 @@ impl ST3215 {
 
     /// Désactiver le torque du servo (Mettre le couple à 0)
     pub fn disable_torque(&self, sts_id: u8) -> Result<(), String> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[0]);
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[0]));
         if comm.is_success() && error == 0 {
             Ok(())
         } else {
@@ -201,9 +1188,8 @@ impl ST3215 {
 
     /// Activer le torque du servo (Mettre le couple à 1)
     pub fn enable_torque(&self, sts_id: u8) -> Result<(), String> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[1]);
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[1]));
         if comm.is_success() && error == 0 {
             Ok(())
         } else {
@@ -211,6 +1197,55 @@ impl ST3215 {
         }
     }
 
+    /// Lire la limite de couple actuelle du servo (en %)
+    pub fn read_torque_limit(&self, sts_id: u8) -> Option<f32> {
+        self.read_register_physical(sts_id, Register::TorqueLimit).ok()
+    }
+
+    /// Limiter le couple du servo (SRAM, perdu à l'extinction)
+    /// percent: couple maximal autorisé (0-100 %)
+    pub fn set_torque_limit(&self, sts_id: u8, percent: f32) -> Result<(), String> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(format!("percent must be between 0 and 100 (got {})", percent));
+        }
+        self.write_register_physical(sts_id, Register::TorqueLimit, percent)
+    }
+
+    /// Limiter le couple de tous les servos du bus (broadcast, sans acquittement)
+    /// Refusé si un servo SCS est connu sur le bus: l'adresse de la limite y est celle du verrou EEPROM
+    pub fn set_torque_limit_all(&self, percent: f32) -> Result<(), String> {
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(format!("percent must be between 0 and 100 (got {})", percent));
+        }
+
+        let has_scs = self.default_protocol == ProtocolVariant::Scs
+            || self
+                .servo_states
+                .lock()
+                .unwrap()
+                .values()
+                .any(|state| state.protocol == ProtocolVariant::Scs);
+        if has_scs {
+            return Err("Torque limit cannot be broadcast on a bus with SCS servos".to_string());
+        }
+
+        let limit = Register::TorqueLimit.spec().from_physical(percent) as u16;
+        let (comm, _) = self.write_with_retry(BROADCAST_ID, |handler| {
+            handler.write_2byte_tx_rx(BROADCAST_ID, STS_TORQUE_LIMIT_L, limit)
+        });
+        if comm.is_success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to broadcast torque limit: comm={:?}", comm))
+        }
+    }
+
+    /// Appliquer un préréglage de souplesse: active le couple avec la limite correspondante
+    pub fn set_compliance(&self, sts_id: u8, compliance: Compliance) -> Result<(), String> {
+        self.set_torque_limit(sts_id, compliance.torque_limit())?;
+        self.enable_torque(sts_id)
+    }
+
     /// Arrêter le servo (Mettre le couple à 0)
     /// 
     /// **Deprecated:** Utilisez `disable_torque` à la place
@@ -230,9 +1265,12 @@ impl ST3215 {
     /// Configurer le mode opérationnel du servo
     /// mode: ID du mode (0, 1, 2 ou 3)
     pub fn set_mode(&self, sts_id: u8, mode: u8) -> Result<(), String> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, _error) = handler.write_tx_rx(sts_id, STS_MODE, &[mode]);
+        if !self.protocol(sts_id).has_mode() {
+            return Err(format!("Servo {} does not support operating modes", sts_id));
+        }
+
+        let (comm, _error) =
+            self.write_with_retry(sts_id, |handler| handler.write_tx_rx(sts_id, STS_MODE, &[mode]));
         if comm.is_success() {
             Ok(())
         } else {
@@ -243,27 +1281,20 @@ impl ST3215 {
     /// Ajouter une correction de position
     /// correction: correction (en steps, peut être négatif)
     pub fn correct_position(&self, sts_id: u8, correction: i16) -> Result<(), String> {
-        let mut corr = correction.abs() as u16;
-        if corr > MAX_CORRECTION {
-            corr = MAX_CORRECTION;
+        if !self.protocol(sts_id).has_offset() {
+            return Err(format!("Servo {} does not support position correction", sts_id));
         }
 
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        
-        let lo = handler.sts_lobyte(corr);
-        let mut hi = handler.sts_hibyte(corr);
-
-        if correction < 0 {
-            hi |= 1 << 3;
+        let max_correction = self.capabilities(sts_id).max_correction;
+        let mut corr = correction.unsigned_abs();
+        if corr > max_correction {
+            corr = max_correction;
         }
 
-        let (comm, _error) = handler.write_tx_rx(sts_id, STS_OFS_L, &[lo, hi]);
-        if comm.is_success() {
-            Ok(())
-        } else {
-            Err("Failed to correct position".to_string())
-        }
+        let value = if correction < 0 { -(corr as i32) } else { corr as i32 };
+        self.eeprom_transaction(sts_id, &[(Register::PositionOffset, value)])
+            .map(|_| ())
+            .map_err(|e| format!("Failed to correct position: {}", e))
     }
 
     /// Commencer la rotation
@@ -271,24 +1302,24 @@ impl ST3215 {
     pub fn rotate(&self, sts_id: u8, speed: i16) -> Result<(), String> {
         self.set_mode(sts_id, 1)?;
 
-        let abs_speed = speed.abs() as u16;
-        let abs_speed = if abs_speed > MAX_SPEED {
-            MAX_SPEED
+        let max_speed = self.capabilities(sts_id).max_speed;
+        let abs_speed = speed.unsigned_abs();
+        let abs_speed = if abs_speed > max_speed {
+            max_speed
         } else {
             abs_speed
         };
 
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        
-        let lo = handler.sts_lobyte(abs_speed);
-        let mut hi = handler.sts_hibyte(abs_speed);
+        let (comm, _error) = self.write_with_retry(sts_id, |handler| {
+            let lo = handler.sts_lobyte(abs_speed);
+            let mut hi = handler.sts_hibyte(abs_speed);
 
-        if speed < 0 {
-            hi |= 1 << 7;
-        }
+            if speed < 0 {
+                hi |= 1 << 7;
+            }
 
-        let (comm, _error) = handler.write_tx_rx(sts_id, STS_GOAL_SPEED_L, &[lo, hi]);
+            handler.write_tx_rx(sts_id, STS_GOAL_SPEED_L, &[lo, hi])
+        });
         if comm.is_success() {
             Ok(())
         } else {
@@ -325,9 +1356,8 @@ impl ST3215 {
 
     /// Définir la position 2048 (Mettre le couple à 128)
     pub fn define_middle(&self, sts_id: u8) -> Option<bool> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[128]);
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_tx_rx(sts_id, STS_TORQUE_ENABLE, &[128]));
         if comm.is_success() && error == 0 {
             Some(true)
         } else {
@@ -356,14 +1386,15 @@ impl ST3215 {
         let max_position = self.get_block_position(sts_id);
 
         if let (Some(mut min_pos), Some(mut max_pos)) = (min_position, max_position) {
+            let max_position = self.capabilities(sts_id).max_position;
             let distance = if min_pos >= max_pos {
-                ((MAX_POSITION - min_pos + max_pos) / 2) as i16
+                ((max_position - min_pos + max_pos) / 2) as i16
             } else {
                 ((max_pos - min_pos) / 2) as i16
             };
 
-            let corr = if min_pos > MAX_POSITION / 2 {
-                min_pos as i16 - MAX_POSITION as i16 - 1
+            let corr = if min_pos > max_position / 2 {
+                min_pos as i16 - max_position as i16 - 1
             } else {
                 min_pos as i16
             };
@@ -382,55 +1413,663 @@ impl ST3215 {
         (None, None)
     }
 
+    /// Lire les seuils de protection du servo
+    pub fn read_protection(&self, sts_id: u8) -> Option<ProtectionSettings> {
+        let mut values = [0; 10];
+        for (value, register) in values.iter_mut().zip(ProtectionSettings::REGISTERS) {
+            *value = self.read_register(sts_id, register).ok()?;
+        }
+        Some(ProtectionSettings::from_registers(values))
+    }
+
+    /// Configurer les seuils de protection du servo (EEPROM)
+    /// Seuls les seuils modifiés sont écrits, dans une seule fenêtre de déverrouillage, puis relus
+    pub fn set_protection(&self, sts_id: u8, settings: &ProtectionSettings) -> Result<(), String> {
+        settings.validate()?;
+        let changes = self.plan_servo(sts_id, &settings.to_registers())?;
+        self.apply_servo(sts_id, &changes)
+    }
+
+    /// Modifier une partie des seuils de protection (lecture, modification, écriture)
+    /// Retourne les seuils appliqués
+    ///
+    /// Exemple: `controller.update_protection(1, |p| p.max_temperature = 70)`
+    pub fn update_protection(
+        &self,
+        sts_id: u8,
+        update: impl FnOnce(&mut ProtectionSettings),
+    ) -> Result<ProtectionSettings, String> {
+        let mut settings = self
+            .read_protection(sts_id)
+            .ok_or_else(|| format!("Could not read protection settings of servo {}", sts_id))?;
+        update(&mut settings);
+        self.set_protection(sts_id, &settings)?;
+        Ok(settings)
+    }
+
+    /// Lire les coefficients PID de la boucle de position
+    pub fn read_pid(&self, sts_id: u8) -> Option<PidGains> {
+        Some(PidGains {
+            p: self.read_register(sts_id, Register::PCoefficient).ok()? as u8,
+            i: self.read_register(sts_id, Register::ICoefficient).ok()? as u8,
+            d: self.read_register(sts_id, Register::DCoefficient).ok()? as u8,
+        })
+    }
+
+    /// Configurer les coefficients PID de la boucle de position (EEPROM)
+    /// Seuls les coefficients modifiés sont écrits, puis relus
+    pub fn set_pid(&self, sts_id: u8, gains: &PidGains) -> Result<(), String> {
+        let targets = [
+            (Register::PCoefficient, gains.p as i32),
+            (Register::ICoefficient, gains.i as i32),
+            (Register::DCoefficient, gains.d as i32),
+        ];
+        for (register, value) in targets {
+            register.spec().validate(value)?;
+        }
+        let changes = self.plan_servo(sts_id, &targets)?;
+        self.apply_servo(sts_id, &changes)
+    }
+
+    /// Mesurer la réponse du servo à un échelon de position
+    /// Le servo est d'abord amené en `from` avec `move_to(id, from, 0, 0, false)`, c'est-à-dire à
+    /// vitesse et accélération maximales du servo: dégager la course avant l'appel.
+    /// La consigne `to` est ensuite envoyée et la position échantillonnée toutes les `sample_period`
+    ///
+    /// duration: durée d'enregistrement
+    /// sample_period: période d'échantillonnage (bornée par la durée d'une lecture sur le bus)
+    /// tolerance: écart à la consigne considéré comme stabilisé (en steps)
+    pub fn step_response(
+        &self,
+        sts_id: u8,
+        from: u16,
+        to: u16,
+        duration: Duration,
+        sample_period: Duration,
+        tolerance: u16,
+    ) -> Result<StepResponse, String> {
+        self.try_move_to(sts_id, from, 0, 0, false)?;
+
+        let start = Instant::now();
+        while start.elapsed() < duration {
+            let tick = Instant::now();
+            let position = self.sync_read_positions(&[sts_id]).get(&sts_id).copied();
+            if position.is_some_and(|pos| (pos as i32 - from as i32).unsigned_abs() <= tolerance as u32) {
+                break;
+            }
+            thread::sleep(sample_period.saturating_sub(tick.elapsed()));
+        }
+
+        self.try_write_position(sts_id, to)?;
+
+        let start = Instant::now();
+        let mut samples = Vec::new();
+        while start.elapsed() < duration {
+            let tick = Instant::now();
+            if let Some(&position) = self.sync_read_positions(&[sts_id]).get(&sts_id) {
+                samples.push((start.elapsed(), position));
+            }
+            thread::sleep(sample_period.saturating_sub(tick.elapsed()));
+        }
+
+        if samples.is_empty() {
+            return Err(format!("Could not sample the position of servo {}", sts_id));
+        }
+        Ok(StepResponse::analyze(from, to, samples, tolerance))
+    }
+
+    /// Ajuster automatiquement les gains PID par essais d'échelons successifs
+    /// ATTENTION: le servo se déplace entre `options.from` et `options.to`, et chaque essai
+    /// réécrit les gains en EEPROM
+    ///
+    /// Les premiers gains satisfaisant les objectifs sont retenus; à défaut, ceux dont la réponse
+    /// s'est stabilisée avec le moins de dépassement, ou à défaut les gains d'origine (premier essai).
+    pub fn tune_pid(&self, sts_id: u8, options: &TuningOptions) -> Result<TuningResult, String> {
+        let original = self
+            .read_pid(sts_id)
+            .ok_or_else(|| format!("Could not read PID gains of servo {}", sts_id))?;
+
+        let mut history: Vec<(PidGains, StepResponse)> = Vec::new();
+        let mut gains = original;
+        for _ in 0..options.max_iterations.max(1) {
+            self.set_pid(sts_id, &gains)?;
+            let response =
+                self.step_response(
+                sts_id,
+                options.from,
+                options.to,
+                options.sample_duration,
+                options.sample_period,
+                options.tolerance,
+            )?;
+            let next = options.suggest(gains, &response);
+            history.push((gains, response));
+
+            match next {
+                Some(next) => gains = next,
+                None => break,
+            }
+        }
+
+        let best = history
+            .iter()
+            .find(|(_, response)| options.is_satisfied(response))
+            .or_else(|| {
+                history
+                    .iter()
+                    .filter(|(_, response)| response.settling_time.is_some())
+                    .min_by(|(_, a), (_, b)| a.overshoot.total_cmp(&b.overshoot))
+            })
+            .unwrap_or(&history[0])
+            .clone();
+
+        self.set_pid(sts_id, &best.0)?;
+        Ok(TuningResult {
+            gains: best.0,
+            satisfied: options.is_satisfied(&best.1),
+            response: best.1,
+            history,
+        })
+    }
+
+    /// Lire les zones mortes du servo (CW, CCW), en steps
+    pub fn read_dead_zones(&self, sts_id: u8) -> Option<(u8, u8)> {
+        let cw = self.read_register(sts_id, Register::CwDeadZone).ok()?;
+        let ccw = self.read_register(sts_id, Register::CcwDeadZone).ok()?;
+        Some((cw as u8, ccw as u8))
+    }
+
+    /// Configurer les zones mortes du servo (EEPROM)
+    /// cw, ccw: largeur de la zone morte de chaque côté de la consigne (0-32 steps)
+    pub fn set_dead_zones(&self, sts_id: u8, cw: u8, ccw: u8) -> Result<(), String> {
+        let targets = [(Register::CwDeadZone, cw as i32), (Register::CcwDeadZone, ccw as i32)];
+        for (register, value) in targets {
+            register.spec().validate(value)?;
+        }
+        let changes = self.plan_servo(sts_id, &targets)?;
+        self.apply_servo(sts_id, &changes)
+    }
+
+    /// Mesurer à partir de quel échelon le servo répond, dans chaque sens, et recommander
+    /// des zones mortes
+    /// ATTENTION: le servo effectue de petits déplacements autour de sa position actuelle, et
+    /// ses zones mortes sont mises à 0 pendant la mesure (puis restaurées)
+    pub fn characterize_deadband(&self, sts_id: u8, options: &DeadbandOptions) -> Result<DeadbandReport, String> {
+        let original = self
+            .read_dead_zones(sts_id)
+            .ok_or_else(|| format!("Could not read dead zones of servo {}", sts_id))?;
+        self.set_dead_zones(sts_id, 0, 0)?;
+
+        let result = self.measure_deadband(sts_id, options);
+        let restored = self.set_dead_zones(sts_id, original.0, original.1);
+        let report = result?;
+        restored?;
+        Ok(report)
+    }
+
+    fn measure_deadband(&self, sts_id: u8, options: &DeadbandOptions) -> Result<DeadbandReport, String> {
+        let read = |st: &Self| {
+            st.read_position(sts_id)
+                .ok_or_else(|| format!("Could not read position of servo {}", sts_id))
+        };
+        let center = read(self)?;
+        self.try_write_position(sts_id, center)?;
+        thread::sleep(options.settle_time);
+
+        let mut rest = Vec::with_capacity(options.jitter_samples);
+        for _ in 0..options.jitter_samples.max(1) {
+            rest.push(read(self)?);
+        }
+        let jitter = rest.iter().max().unwrap_or(&center) - rest.iter().min().unwrap_or(&center);
+
+        let max_position = self.capabilities(sts_id).max_position;
+        let mut thresholds = [None, None];
+        for (threshold, direction) in thresholds.iter_mut().zip([-1i32, 1]) {
+            for step in 1..=options.max_step {
+                self.try_write_position(sts_id, center)?;
+                thread::sleep(options.settle_time);
+                let base = read(self)?;
+
+                let target = (base as i32 + direction * step as i32).clamp(0, max_position as i32) as u16;
+                self.try_write_position(sts_id, target)?;
+                thread::sleep(options.settle_time);
+
+                let moved = (read(self)? as i32 - base as i32) * direction;
+                if moved > jitter as i32 {
+                    *threshold = Some(step);
+                    break;
+                }
+            }
+        }
+        self.try_write_position(sts_id, center)?;
+
+        let [cw_threshold, ccw_threshold] = thresholds;
+        Ok(DeadbandReport {
+            cw_threshold,
+            ccw_threshold,
+            jitter,
+            recommended: (
+                DeadbandReport::recommend(cw_threshold, jitter, options.max_step),
+                DeadbandReport::recommend(ccw_threshold, jitter, options.max_step),
+            ),
+        })
+    }
+
+    /// Lire les limites d'angle du servo (min, max) en steps
+    /// (0, 0) désactive les limites (rotation multi-tours)
+    pub fn read_angle_limits(&self, sts_id: u8) -> Option<(u16, u16)> {
+        let min = self.read_register(sts_id, Register::MinAngleLimit).ok()? as u16;
+        let max = self.read_register(sts_id, Register::MaxAngleLimit).ok()? as u16;
+        self.update_state(sts_id, |state| state.angle_limits = Some((min, max)));
+        Some((min, max))
+    }
+
+    /// Configurer les limites d'angle du servo (EEPROM)
+    /// min, max: positions extrêmes autorisées, en steps (0 et 0 pour désactiver les limites)
+    pub fn set_angle_limits(&self, sts_id: u8, min: u16, max: u16) -> Result<(), String> {
+        let max_position = self.capabilities(sts_id).max_position;
+        if max > max_position || min > max {
+            return Err(format!(
+                "Angle limits must satisfy 0 <= min <= max <= {} (got {}..{})",
+                max_position, min, max
+            ));
+        }
+
+        self.eeprom_transaction(
+            sts_id,
+            &[(Register::MinAngleLimit, min as i32), (Register::MaxAngleLimit, max as i32)],
+        )?;
+        self.update_state(sts_id, |state| state.angle_limits = Some((min, max)));
+        Ok(())
+    }
+
+    /// Choisir le contrôle côté hôte des consignes de position (`write_position`, `move_to`)
+    pub fn set_limit_enforcement(&self, enforcement: LimitEnforcement) {
+        *self.limit_enforcement.lock().unwrap() = enforcement;
+    }
+
+    pub fn limit_enforcement(&self) -> LimitEnforcement {
+        *self.limit_enforcement.lock().unwrap()
+    }
+
+    /// Vérifier une consigne de position par rapport à la plage du modèle et aux limites
+    /// d'angle du servo
+    /// Les limites sont lues sur le servo au premier appel, puis mémorisées
+    ///
+    /// En mode `Disabled`, la consigne est transmise telle quelle. Avec des limites `(0, 0)`
+    /// (multi-tours, mode pas à pas), seule la plage du registre est vérifiée: la consigne est
+    /// alors signée (bit 15, jusqu'à ±30719 steps) et peut dépasser la position maximale du modèle.
+    ///
+    /// Retourne la consigne à envoyer (éventuellement ramenée dans les limites en mode `Clamp`),
+    /// ou une erreur explicite en mode `Reject`
+    pub fn check_position(&self, sts_id: u8, position: u16) -> Result<u16, String> {
+        let enforcement = self.limit_enforcement();
+        if enforcement == LimitEnforcement::Disabled {
+            return Ok(position);
+        }
+
+        let (min, max) = match self.servo_state(sts_id).angle_limits {
+            Some(limits) => limits,
+            None => self
+                .read_angle_limits(sts_id)
+                .ok_or_else(|| format!("Could not read angle limits of servo {}", sts_id))?,
+        };
+        if (min, max) == (0, 0) {
+            let goal = Register::GoalPosition.spec();
+            goal.validate(goal.encoding.decode(position))?;
+            return Ok(position);
+        }
+
+        self.capabilities(sts_id).validate_position(position)?;
+        if (min..=max).contains(&position) {
+            return Ok(position);
+        }
+
+        match enforcement {
+            LimitEnforcement::Clamp => Ok(position.clamp(min, max)),
+            _ => Err(format!(
+                "Position {} is outside the angle limits of servo {} ({}..{})",
+                position, sts_id, min, max
+            )),
+        }
+    }
+
     /// Déplacer le servo vers une position prédéfinie
     /// position: Nouvelle position du servo
     /// speed: Vitesse de déplacement en step/s (facultatif, 2400 par défaut)
     /// acc: Vitesse d'accélération en step/s² (facultatif, 50 par défaut)
     /// wait: Attendre que la position soit atteinte avant le retour de la fonction
     pub fn move_to(&self, sts_id: u8, position: u16, speed: u16, acc: u8, wait: bool) -> Option<bool> {
-        self.set_mode(sts_id, 0).ok()?;
-        self.set_acceleration(sts_id, acc)?;
-        self.set_speed(sts_id, speed)?;
+        self.try_move_to(sts_id, position, speed, acc, wait).ok()
+    }
+
+    /// Comme `move_to`, mais retourne la cause de l'échec (limites d'angle, erreur de communication)
+    pub fn try_move_to(&self, sts_id: u8, position: u16, speed: u16, acc: u8, wait: bool) -> Result<bool, String> {
+        let position = self.check_position(sts_id, position)?;
+        let protocol = self.protocol(sts_id);
+        if protocol.has_mode() {
+            self.set_mode(sts_id, 0)?;
+        }
+        if protocol.has_acceleration() {
+            self.set_acceleration(sts_id, acc)
+                .ok_or_else(|| format!("Could not set acceleration of servo {}", sts_id))?;
+        }
+        self.set_speed(sts_id, speed)
+            .ok_or_else(|| format!("Could not set speed of servo {}", sts_id))?;
 
-        let curr_pos = self.read_position(sts_id)?;
+        let curr_pos = self
+            .read_position(sts_id)
+            .ok_or_else(|| format!("Could not read position of servo {}", sts_id))?;
 
-        self.write_position(sts_id, position)?;
+        self.try_write_position(sts_id, position)?;
 
         if wait {
+            // Les servos SCS n'ont pas de rampe, et une accélération nulle désactive la rampe:
+            // on approxime une accélération maximale. Une vitesse nulle est la vitesse maximale.
+            let acc = if protocol.has_acceleration() && acc > 0 { acc } else { u8::MAX };
+            let speed = if speed == 0 { self.capabilities(sts_id).max_speed } else { speed };
             let distance = (position as i32 - curr_pos as i32).abs() as f64;
-            let time_to_speed = speed as f64 / (acc as f64 * 100.0);
-            let distance_acc = 0.5 * (acc as f64 * 100.0) * time_to_speed.powi(2);
+            // Même profil que `move_coordinated` (accélération du registre en 100 step/s²)
+            let time_wait = trapezoid_duration(distance, speed as f64, acc as f64 * 100.0);
+
+            thread::sleep(Duration::from_secs_f64(time_wait));
+        }
+
+        Ok(true)
+    }
+
+    /// Déplacer plusieurs servos en une seule trame SYNC_WRITE (GroupSyncWrite)
+    /// moves: (ID, position, vitesse en step/s, accélération en 100 step/s²) pour chaque servo
+    ///
+    /// Tous les servos reçoivent leur consigne au même instant. Les positions passent par
+    /// `check_position`, la vitesse et l'accélération sont bornées aux capacités de chaque modèle.
+    /// Les servos doivent être en mode position et utiliser la même variante de protocole.
+    /// Une trame contient au plus 30 servos STS (34 servos SCS): au-delà, une erreur est retournée.
+    pub fn move_many(&self, moves: &[(u8, u16, u16, u8)]) -> Result<(), String> {
+        let Some(&(first_id, ..)) = moves.first() else {
+            return Ok(());
+        };
+
+        let ids: Vec<u8> = moves.iter().map(|&(sts_id, ..)| sts_id).collect();
+        let protocol = self.check_sync_group(&ids)?;
+        let mut targets: Vec<(u8, u16, u16, u8)> = Vec::with_capacity(moves.len());
+        for &(sts_id, position, speed, acc) in moves {
+            let spec = self.capabilities(sts_id);
+            let position = self.check_position(sts_id, position)?;
+            targets.push((sts_id, position, speed.min(spec.max_speed), acc.min(spec.max_acceleration)));
+        }
+
+        // Les servos SCS n'ont pas de registre d'accélération: la trame commence à la position
+        let with_acceleration = protocol.has_acceleration();
+        let mut scs_group = GroupSyncWrite::new(STS_GOAL_POSITION_L, 6);
+        let mut sts_group = self.group_sync_write.lock().unwrap();
+        let group = if with_acceleration { &mut *sts_group } else { &mut scs_group };
+        if targets.len() > group.max_servos() {
+            return Err(format!(
+                "Too many servos for one SYNC_WRITE frame ({} > {})",
+                targets.len(),
+                group.max_servos()
+            ));
+        }
+
+        // Le gestionnaire de paquets du premier servo donne l'ordre des octets de la variante
+        let (comm, _error) = self.write_with_retry(first_id, |handler| {
+            group.clear_param();
+            for &(sts_id, position, speed, acc) in &targets {
+                let mut data = Vec::with_capacity(7);
+                if with_acceleration {
+                    data.push(acc);
+                }
+                data.extend([
+                    handler.sts_lobyte(position),
+                    handler.sts_hibyte(position),
+                    0,
+                    0,
+                    handler.sts_lobyte(speed),
+                    handler.sts_hibyte(speed),
+                ]);
+                group.add_param(sts_id, data);
+            }
+            (group.tx_packet(handler), 0)
+        });
+
+        if comm.is_success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to send synchronized move: comm={:?}", comm))
+        }
+    }
+
+    /// Vérifier qu'un groupe de servos peut partager une trame SYNC_WRITE: même variante de
+    /// protocole, chaque ID une seule fois
+    /// Retourne la variante de protocole du groupe
+    fn check_sync_group(&self, ids: &[u8]) -> Result<ProtocolVariant, String> {
+        let Some(&first_id) = ids.first() else {
+            return Ok(self.default_protocol);
+        };
+
+        let protocol = self.protocol(first_id);
+        for (i, &sts_id) in ids.iter().enumerate() {
+            if self.protocol(sts_id) != protocol {
+                return Err(format!(
+                    "Servo {} does not use the same protocol variant as servo {}",
+                    sts_id, first_id
+                ));
+            }
+            if ids[..i].contains(&sts_id) {
+                return Err(format!("Servo {} appears more than once", sts_id));
+            }
+        }
+        Ok(protocol)
+    }
+
+    /// Déplacer plusieurs servos pour qu'ils arrivent tous au même instant
+    /// targets: (ID, position) pour chaque servo
+    /// timing: durée imposée, ou vitesse et accélération maximales (le servo le plus éloigné fixe la durée)
+    ///
+    /// La vitesse et l'accélération de chaque servo sont calculées sur un profil trapézoïdal
+    /// (avec une durée imposée: accélération, palier et décélération d'un tiers de la durée chacun),
+    /// puis toutes les consignes sont envoyées en une seule trame (`move_many`).
+    /// Retourne la durée prévue du mouvement, après arrondi des consignes.
+    pub fn move_coordinated(&self, targets: &[(u8, u16)], timing: MoveTiming) -> Result<Duration, String> {
+        if targets.is_empty() {
+            return Ok(Duration::ZERO);
+        }
+
+        let ids: Vec<u8> = targets.iter().map(|&(sts_id, _)| sts_id).collect();
+        let current = self.sync_read_positions(&ids);
+
+        let mut distances = Vec::with_capacity(targets.len());
+        for &(sts_id, position) in targets {
+            let position = self.check_position(sts_id, position)?;
+            let start = current
+                .get(&sts_id)
+                .copied()
+                .or_else(|| self.read_position(sts_id))
+                .ok_or_else(|| format!("Could not read position of servo {}", sts_id))?;
+            distances.push((sts_id, position, (position as f64 - start as f64).abs()));
+        }
 
-            let time_wait = if distance_acc >= distance {
-                (2.0 * distance / acc as f64).sqrt()
+        // Les servos SCS n'ont pas de rampe: on approxime une accélération maximale, comme `move_to`
+        let acceleration = |sts_id: u8, acc: u8| {
+            if self.protocol(sts_id).has_acceleration() {
+                acc as f64 * 100.0
             } else {
-                let remain_distance = distance - distance_acc;
-                time_to_speed + (remain_distance / speed as f64)
+                u8::MAX as f64 * 100.0
+            }
+        };
+
+        let duration = match timing {
+            MoveTiming::Duration(duration) => duration.as_secs_f64(),
+            MoveTiming::Limits { speed, acc } => distances
+                .iter()
+                .map(|&(sts_id, _, distance)| {
+                    let spec = self.capabilities(sts_id);
+                    let acc = acceleration(sts_id, acc.min(spec.max_acceleration).max(1));
+                    trapezoid_duration(distance, speed.min(spec.max_speed) as f64, acc)
+                })
+                .fold(0.0, f64::max),
+        };
+
+        let mut moves = Vec::with_capacity(distances.len());
+        let mut expected: f64 = 0.0;
+        for (sts_id, position, distance) in distances {
+            let spec = self.capabilities(sts_id);
+            let acc = match timing {
+                MoveTiming::Limits { acc, .. } => acc,
+                MoveTiming::Duration(_) if duration > 0.0 => {
+                    (4.5 * distance / duration.powi(2) / 100.0).ceil().min(u8::MAX as f64) as u8
+                }
+                MoveTiming::Duration(_) => spec.max_acceleration,
             };
+            let acc = acc.min(spec.max_acceleration).max(1);
 
-            thread::sleep(Duration::from_secs_f64(time_wait));
+            // Une vitesse nulle correspond à la vitesse maximale: au moins 1 step/s
+            let speed = if distance == 0.0 {
+                1.0
+            } else {
+                trapezoid_speed(distance, duration, acceleration(sts_id, acc))
+                    .map(f64::ceil)
+                    .filter(|&speed| speed <= spec.max_speed as f64)
+                    .ok_or_else(|| {
+                        format!(
+                            "Servo {} cannot travel {} steps in {:.3} s",
+                            sts_id, distance, duration
+                        )
+                    })?
+                    .max(1.0)
+            };
+
+            expected = expected.max(trapezoid_duration(distance, speed, acceleration(sts_id, acc)));
+            moves.push((sts_id, position, speed as u16, acc));
+        }
+
+        self.move_many(&moves)?;
+        Ok(Duration::from_secs_f64(expected))
+    }
+
+    /// Écrire la position de plusieurs servos en une seule trame SYNC_WRITE
+    /// Ni les positions ni le groupe ne sont vérifiés (`check_position`, `check_sync_group`):
+    /// les consignes sont encodées avec l'ordre des octets du premier servo
+    pub(crate) fn sync_write_positions(&self, positions: &[(u8, u16)]) -> Result<(), String> {
+        let Some(&(first_id, _)) = positions.first() else {
+            return Ok(());
+        };
+
+        let mut group = GroupSyncWrite::new(STS_GOAL_POSITION_L, 2);
+        let (comm, _error) = self.write_with_retry(first_id, |handler| {
+            group.clear_param();
+            for &(sts_id, position) in positions {
+                group.add_param(sts_id, vec![handler.sts_lobyte(position), handler.sts_hibyte(position)]);
+            }
+            (group.tx_packet(handler), 0)
+        });
+
+        if comm.is_success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to send goal positions: comm={:?}", comm))
+        }
+    }
+
+    /// Calculer une trajectoire côté hôte depuis les positions actuelles
+    /// targets: (ID, position d'arrivée) pour chaque servo
+    pub fn plan_trajectory(
+        &self,
+        targets: &[(u8, u16)],
+        kind: ProfileKind,
+        limits: &TrajectoryLimits,
+    ) -> Result<Trajectory, String> {
+        let ids: Vec<u8> = targets.iter().map(|&(sts_id, _)| sts_id).collect();
+        let current = self.sync_read_positions(&ids);
+
+        let mut moves = Vec::with_capacity(targets.len());
+        for &(sts_id, target) in targets {
+            let target = self.check_position(sts_id, target)?;
+            let start = current
+                .get(&sts_id)
+                .copied()
+                .or_else(|| self.read_position(sts_id))
+                .ok_or_else(|| format!("Could not read position of servo {}", sts_id))?;
+            moves.push((sts_id, start, target));
+        }
+        Trajectory::new(&moves, kind, limits)
+    }
+
+    /// Préparer la diffusion d'une trajectoire à cadence fixe (voir `TrajectoryStream::start`)
+    /// period: intervalle entre deux consignes
+    ///
+    /// Les servos passent en mode position, sans rampe d'accélération et à vitesse maximale,
+    /// pour suivre directement les consignes.
+    ///
+    /// Les positions de départ et d'arrivée passent par `check_position` (les consignes
+    /// intermédiaires restent entre les deux): une position hors limites est refusée, même en
+    /// mode `Clamp`. Les servos doivent utiliser la même variante de protocole et tenir dans une
+    /// seule trame SYNC_WRITE.
+    pub fn trajectory_stream(&self, trajectory: Trajectory, period: Duration) -> Result<TrajectoryStream, String> {
+        let ids = trajectory.ids();
+        self.check_sync_group(&ids)?;
+        let max_servos = GroupSyncWrite::new(STS_GOAL_POSITION_L, 2).max_servos();
+        if ids.len() > max_servos {
+            return Err(format!(
+                "Too many servos for one SYNC_WRITE frame ({} > {})",
+                ids.len(),
+                max_servos
+            ));
+        }
+        for &(sts_id, start, target) in trajectory.moves() {
+            for position in [start, target] {
+                if self.check_position(sts_id, position)? != position {
+                    return Err(format!(
+                        "Position {} is outside the angle limits of servo {}",
+                        position, sts_id
+                    ));
+                }
+            }
         }
 
-        Some(true)
+        for sts_id in ids {
+            let protocol = self.protocol(sts_id);
+            if protocol.has_mode() {
+                self.set_mode(sts_id, 0)?;
+            }
+            if protocol.has_acceleration() {
+                self.set_acceleration(sts_id, 0)
+                    .ok_or_else(|| format!("Failed to set acceleration of servo {}", sts_id))?;
+            }
+            self.set_speed(sts_id, self.capabilities(sts_id).max_speed)
+                .ok_or_else(|| format!("Failed to set speed of servo {}", sts_id))?;
+        }
+        Ok(TrajectoryStream::new(self.clone(), trajectory, period))
     }
 
     /// Écrire la position
+    /// Retourne None si la consigne est refusée par `check_position`
     pub fn write_position(&self, sts_id: u8, position: u16) -> Option<bool> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (comm, error) = handler.write_2byte_tx_rx(sts_id, STS_GOAL_POSITION_L, position);
+        self.try_write_position(sts_id, position).ok()
+    }
+
+    /// Comme `write_position`, mais retourne la cause de l'échec (limites d'angle, erreur de communication)
+    pub fn try_write_position(&self, sts_id: u8, position: u16) -> Result<bool, String> {
+        let position = self.check_position(sts_id, position)?;
+        let (comm, error) =
+            self.write_with_retry(sts_id, |handler| handler.write_2byte_tx_rx(sts_id, STS_GOAL_POSITION_L, position));
         if comm.is_success() && error == 0 {
-            Some(true)
+            Ok(true)
         } else {
-            None
+            Err(format!("Failed to write position of servo {}: comm={:?}", sts_id, comm))
         }
     }
 
     /// Obtenir le statut des capteurs
     pub fn read_status(&self, sts_id: u8) -> Option<HashMap<String, bool>> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (status_byte, comm, error) = handler.read_1byte_tx_rx(sts_id, STS_STATUS);
+        // Registre de statut absent de la table de contrôle SCS
+        if self.protocol(sts_id) == ProtocolVariant::Scs {
+            return None;
+        }
+
+        let (status_byte, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_1byte_tx_rx(sts_id, STS_STATUS));
         
         if !comm.is_success() || error != 0 {
             return None;
@@ -455,9 +2094,8 @@ impl ST3215 {
 
     /// Obtenir la position actuelle
     pub fn read_position(&self, sts_id: u8) -> Option<u16> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (position, comm, error) = handler.read_2byte_tx_rx(sts_id, STS_PRESENT_POSITION_L);
+        let (position, comm, error) =
+            self.read_with_retry(sts_id, |handler| handler.read_2byte_tx_rx(sts_id, STS_PRESENT_POSITION_L));
         if comm.is_success() && error == 0 {
             Some(position)
         } else {
@@ -465,30 +2103,120 @@ impl ST3215 {
         }
     }
 
+    /// Lire la position de plusieurs servos en une seule transaction (GroupSyncRead)
+    /// Les servos qui n'ont pas répondu sont absents du résultat
+    pub fn sync_read_positions(&self, ids: &[u8]) -> HashMap<u8, u16> {
+        let Some(&first_id) = ids.first() else {
+            return HashMap::new();
+        };
+
+        let mut group = GroupSyncRead::new(STS_PRESENT_POSITION_L, 2);
+        for &sts_id in ids {
+            group.add_param(sts_id);
+        }
+
+        let (positions, _comm, _error) = self.read_with_retry(first_id, |handler| {
+            let comm = group.tx_rx_packet(handler);
+            let mut positions = HashMap::new();
+            for &sts_id in ids {
+                if group.is_available(sts_id, STS_PRESENT_POSITION_L, 2).0 {
+                    let position = group.get_data(handler, sts_id, STS_PRESENT_POSITION_L, 2) as u16;
+                    positions.insert(sts_id, position);
+                }
+            }
+            // Réponse partielle: les positions reçues restent exploitables
+            let comm = if positions.is_empty() { comm } else { CommResult::Success };
+            (positions, comm, 0)
+        });
+        positions
+    }
+
     /// Obtenir la vitesse actuelle
     pub fn read_speed(&self, sts_id: u8) -> Option<i16> {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        let (speed, comm, error) = handler.read_2byte_tx_rx(sts_id, STS_PRESENT_SPEED_L);
+        let (speed, comm, error) = self.read_with_retry(sts_id, |handler| {
+            let (speed, comm, error) = handler.read_2byte_tx_rx(sts_id, STS_PRESENT_SPEED_L);
+            (handler.sts_tohost(speed, 15), comm, error)
+        });
         if comm.is_success() && error == 0 {
-            Some(handler.sts_tohost(speed, 15))
+            Some(speed)
         } else {
             None
         }
     }
 
+    /// Lire la position actuelle sous forme d'angle, selon la résolution du modèle
+    pub fn read_angle(&self, sts_id: u8) -> Option<Angle> {
+        let position = self.read_position(sts_id)?;
+        Some(Angle::from_steps(position as f32, self.capabilities(sts_id)))
+    }
+
+    /// Lire la vitesse actuelle sous forme de vitesse angulaire, selon la résolution du modèle
+    pub fn read_angular_velocity(&self, sts_id: u8) -> Option<AngularVelocity> {
+        let speed = self.read_speed(sts_id)?;
+        Some(AngularVelocity::from_steps_per_sec(speed as f32, self.capabilities(sts_id)))
+    }
+
+    /// Déplacer le servo vers un angle, comme `move_to`
+    /// Les valeurs sont converties selon la résolution du modèle; la vitesse et l'accélération
+    /// sont bornées aux plages des registres
+    /// Retourne None si l'angle est hors de la plage du modèle, ou si la vitesse est inférieure
+    /// à 1 step/s (le servo interpréterait la valeur 0 comme sa vitesse maximale)
+    pub fn move_to_angle(
+        &self,
+        sts_id: u8,
+        angle: Angle,
+        velocity: AngularVelocity,
+        acceleration: AngularAcceleration,
+        wait: bool,
+    ) -> Option<bool> {
+        self.try_move_to_angle(sts_id, angle, velocity, acceleration, wait).ok()
+    }
+
+    /// Comme `move_to_angle`, mais retourne la cause de l'échec
+    pub fn try_move_to_angle(
+        &self,
+        sts_id: u8,
+        angle: Angle,
+        velocity: AngularVelocity,
+        acceleration: AngularAcceleration,
+        wait: bool,
+    ) -> Result<bool, String> {
+        let spec = self.capabilities(sts_id);
+        let position = angle.to_steps(spec);
+        if position < 0.0 || position > spec.max_position as f32 {
+            return Err(format!(
+                "Angle {} is outside the range of {} (0..{} steps)",
+                angle, spec.name, spec.max_position
+            ));
+        }
+
+        let speed = velocity.to_steps_per_sec(spec).abs();
+        if speed.is_nan() || speed < 1.0 {
+            return Err(format!(
+                "Velocity {} is below 1 step/s for {} (0 would mean maximum speed)",
+                velocity, spec.name
+            ));
+        }
+        let acc = acceleration.to_register(spec);
+        self.try_move_to(sts_id, position as u16, speed.min(spec.max_speed as f32) as u16, acc, wait)
+    }
+
     /// Verrouiller l'EEPROM du servo
     pub fn lock_eprom(&self, sts_id: u8) -> CommResult {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        handler.write_1byte_tx_only(sts_id, STS_LOCK, 1)
+        let (comm, _) = self.write_with_retry(sts_id, |handler| {
+            let lock_address = handler.protocol().lock_address();
+            (handler.write_1byte_tx_only(sts_id, lock_address, 1), 0)
+        });
+        comm
     }
 
     /// Déverrouiller l'EEPROM du servo
     pub fn unlock_eprom(&self, sts_id: u8) -> CommResult {
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        handler.write_1byte_tx_only(sts_id, STS_LOCK, 0)
+        let (comm, _) = self.write_with_retry(sts_id, |handler| {
+            let lock_address = handler.protocol().lock_address();
+            (handler.write_1byte_tx_only(sts_id, lock_address, 0), 0)
+        });
+        comm
     }
 
     /// Changer l'ID d'un servo
@@ -503,19 +2231,133 @@ impl ST3215 {
             return Err(format!("Could not find servo: {}", sts_id));
         }
 
-        if !self.unlock_eprom(sts_id).is_success() {
-            return Err("Could not unlock Eprom".to_string());
+        if new_id != sts_id && self.ping_servo(new_id) {
+            return Err(format!("ID {} is already used by another servo", new_id));
         }
 
-        let mut port = self.port_handler.lock().unwrap();
-        let mut handler = ProtocolPacketHandler::new(&mut *port);
-        if !handler.write_1byte_tx_only(sts_id, STS_ID, new_id).is_success() {
-            return Err("Could not change Servo ID".to_string());
-        }
+        self.eeprom_transaction(sts_id, &[(Register::Id, new_id as i32)])
+            .map(|_| ())
+            .map_err(|e| format!("Could not change Servo ID: {}", e))
+    }
 
-        drop(port);
-        let _ = self.lock_eprom(sts_id);
-        
+    /// Changer la vitesse du bus d'un servo (EEPROM)
+    /// baud_code: valeur du registre (`STS_1M` à `STS_38400`)
+    ///
+    /// Le port de l'hôte passe à la nouvelle vitesse pour vérifier l'écriture, et y reste:
+    /// les autres servos du bus doivent être changés au préalable ou séparément
+    pub fn set_baud_rate(&self, sts_id: u8, baud_code: u8) -> Result<(), String> {
+        self.eeprom_transaction(sts_id, &[(Register::BaudRate, baud_code as i32)])?;
         Ok(())
     }
+
+    /// Vitesse actuelle du port de l'hôte (en bauds)
+    pub fn port_baud_rate(&self) -> u32 {
+        self.port_handler.lock().unwrap_or_else(PoisonError::into_inner).baudrate()
+    }
+
+    /// Changer la vitesse du port de l'hôte (en bauds), sans modifier les servos
+    pub fn set_port_baud_rate(&self, baudrate: u32) -> Result<(), String> {
+        self.port_handler.lock().unwrap_or_else(PoisonError::into_inner).set_baudrate(baudrate)
+    }
+
+    /// Mettre en service un servo neuf, seul sur le bus
+    /// new_id: ID à attribuer (voir `ProvisioningPlan::next_id`)
+    ///
+    /// Le port repasse à la vitesse d'usine, le servo est détecté à l'ID d'usine, puis l'ID,
+    /// la vitesse du bus, la correction, les limites d'angle et le mode sont écrits dans une seule
+    /// transaction EEPROM. Le servo est ensuite relu à son nouvel ID pour établir son étiquette.
+    /// Le port reste à la vitesse configurée.
+    pub fn provision(&self, plan: &ProvisioningPlan, new_id: u8) -> Result<ServoLabel, String> {
+        if new_id > 253 {
+            return Err("new_id must be between 0 and 253".to_string());
+        }
+
+        let factory_bps = baud_rate_bps(plan.factory_baud_rate)
+            .ok_or_else(|| format!("Invalid factory baud rate code: {}", plan.factory_baud_rate))?;
+        self.set_port_baud_rate(factory_bps)?;
+
+        let factory_id = plan.factory_id;
+        if !self.ping_servo(factory_id) {
+            return Err(format!("No servo found at factory ID {}", factory_id));
+        }
+        if new_id != factory_id && self.ping_servo(new_id) {
+            return Err(format!("ID {} is already used by another servo", new_id));
+        }
+
+        let info = self.identify(factory_id)?;
+        let spec = self.capabilities(factory_id);
+
+        let mut writes = Vec::new();
+        if let Some(offset) = plan.position_offset {
+            if offset.unsigned_abs() > spec.max_correction {
+                return Err(format!(
+                    "Position offset must be between -{} and {} for {} (got {})",
+                    spec.max_correction, spec.max_correction, spec.name, offset
+                ));
+            }
+            writes.push((Register::PositionOffset, offset as i32));
+        }
+        if let Some((min, max)) = plan.angle_limits {
+            if max > spec.max_position || min > max {
+                return Err(format!(
+                    "Angle limits must satisfy 0 <= min <= max <= {} (got {}..{})",
+                    spec.max_position, min, max
+                ));
+            }
+            writes.push((Register::MinAngleLimit, min as i32));
+            writes.push((Register::MaxAngleLimit, max as i32));
+        }
+        if let Some(mode) = plan.mode {
+            writes.push((Register::Mode, mode as i32));
+        }
+        writes.push((Register::Id, new_id as i32));
+        if let Some(baud_rate) = plan.baud_rate {
+            writes.push((Register::BaudRate, baud_rate as i32));
+        }
+
+        let sts_id = self
+            .eeprom_transaction(factory_id, &writes)
+            .map_err(|e| format!("Could not provision servo: {}", e))?;
+
+        if !self.ping_servo(sts_id) {
+            return Err(format!("Servo does not answer at its new ID {}", sts_id));
+        }
+
+        let protocol = self.protocol(sts_id);
+        let label = ServoLabel {
+            id: sts_id,
+            model: info.model_name().to_string(),
+            model_number: info.model_number,
+            firmware: info.firmware_version(),
+            baud_rate: self.read_register(sts_id, Register::BaudRate)? as u8,
+            position_offset: if protocol.has_offset() {
+                self.read_register(sts_id, Register::PositionOffset)? as i16
+            } else {
+                0
+            },
+            angle_limits: (
+                self.read_register(sts_id, Register::MinAngleLimit)? as u16,
+                self.read_register(sts_id, Register::MaxAngleLimit)? as u16,
+            ),
+            mode: if protocol.has_mode() {
+                self.read_register(sts_id, Register::Mode)? as u8
+            } else {
+                0
+            },
+            provisioned_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+        };
+
+        let mismatch = plan.baud_rate.is_some_and(|baud_rate| baud_rate != label.baud_rate)
+            || plan.position_offset.is_some_and(|offset| offset != label.position_offset)
+            || plan.angle_limits.is_some_and(|limits| limits != label.angle_limits)
+            || plan.mode.is_some_and(|mode| mode != label.mode);
+        if mismatch {
+            return Err(format!("Verification failed for servo {}: {:?}", sts_id, label));
+        }
+
+        Ok(label)
+    }
 }
\ No newline at end of file
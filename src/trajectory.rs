@@ -0,0 +1,486 @@
+use crate::st3215::ST3215;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Forme du profil de vitesse généré côté hôte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProfileKind {
+    /// Accélération constante (saut d'accélération au début et à la fin des rampes)
+    #[default]
+    Trapezoidal,
+    /// Jerk limité: l'accélération varie progressivement (profil en S)
+    SCurve,
+}
+
+/// Limites du profil, appliquées au servo qui a la plus grande distance à parcourir
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrajectoryLimits {
+    /// Vitesse maximale (en step/s)
+    pub max_velocity: f64,
+    /// Accélération maximale (en step/s²)
+    pub max_acceleration: f64,
+    /// Jerk maximal (en step/s³, ignoré pour un profil trapézoïdal)
+    pub max_jerk: f64,
+}
+
+impl Default for TrajectoryLimits {
+    fn default() -> Self {
+        Self {
+            max_velocity: 2000.0,
+            max_acceleration: 4000.0,
+            max_jerk: 20000.0,
+        }
+    }
+}
+
+/// Profil de déplacement à une dimension, symétrique (rampe d'accélération, palier, rampe de décélération)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Profile {
+    distance: f64,
+    /// Vitesse de palier atteinte
+    velocity: f64,
+    /// Accélération maximale atteinte
+    acceleration: f64,
+    /// Jerk (infini pour un profil trapézoïdal)
+    jerk: f64,
+    /// Durée de chaque phase à jerk non nul
+    jerk_time: f64,
+    /// Durée de la rampe d'accélération
+    ramp_time: f64,
+    /// Durée du palier
+    cruise_time: f64,
+}
+
+impl Profile {
+    fn new(distance: f64, kind: ProfileKind, limits: &TrajectoryLimits) -> Self {
+        let acc = limits.max_acceleration;
+        let jerk = match kind {
+            ProfileKind::Trapezoidal => f64::INFINITY,
+            ProfileKind::SCurve => limits.max_jerk,
+        };
+
+        // Durée de la rampe pour atteindre une vitesse donnée, et phases à jerk non nul
+        let ramp = |velocity: f64| {
+            if velocity * jerk >= acc * acc {
+                let jerk_time = acc / jerk;
+                (velocity / acc + jerk_time, jerk_time, acc)
+            } else {
+                let jerk_time = (velocity / jerk).sqrt();
+                (2.0 * jerk_time, jerk_time, jerk * jerk_time)
+            }
+        };
+
+        // Vitesse de palier réduite si la distance ne permet pas de l'atteindre
+        // (la distance parcourue pendant les deux rampes vaut velocity × ramp_time)
+        let mut velocity = limits.max_velocity;
+        if velocity * ramp(velocity).0 > distance {
+            let (mut low, mut high) = (0.0, velocity);
+            for _ in 0..60 {
+                let mid = (low + high) / 2.0;
+                if mid * ramp(mid).0 > distance {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            velocity = low;
+        }
+
+        let (ramp_time, jerk_time, acceleration) = ramp(velocity);
+        let cruise_time = if velocity > 0.0 {
+            ((distance - velocity * ramp_time) / velocity).max(0.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            distance,
+            velocity,
+            acceleration,
+            jerk,
+            jerk_time,
+            ramp_time,
+            cruise_time,
+        }
+    }
+
+    fn duration(&self) -> f64 {
+        2.0 * self.ramp_time + self.cruise_time
+    }
+
+    /// Position pendant la rampe d'accélération (0 <= t <= ramp_time)
+    fn ramp_position(&self, t: f64) -> f64 {
+        let jerk_phase = |t: f64| {
+            if self.jerk.is_finite() {
+                self.jerk * t.powi(3) / 6.0
+            } else {
+                0.0
+            }
+        };
+
+        let constant_end = self.ramp_time - self.jerk_time;
+        if t <= self.jerk_time {
+            jerk_phase(t)
+        } else if t <= constant_end {
+            let v1 = self.acceleration * self.jerk_time / 2.0;
+            let tau = t - self.jerk_time;
+            jerk_phase(self.jerk_time) + v1 * tau + self.acceleration * tau * tau / 2.0
+        } else {
+            // La rampe est symétrique: v(t) + v(ramp_time - t) = velocity
+            let ramp_distance = self.velocity * self.ramp_time / 2.0;
+            self.velocity * t - ramp_distance + jerk_phase(self.ramp_time - t)
+        }
+    }
+
+    /// Position à l'instant t (en steps depuis le départ)
+    fn position(&self, t: f64) -> f64 {
+        let ramp_distance = self.velocity * self.ramp_time / 2.0;
+        let duration = self.duration();
+        if t <= 0.0 {
+            0.0
+        } else if t >= duration {
+            self.distance
+        } else if t <= self.ramp_time {
+            self.ramp_position(t)
+        } else if t <= self.ramp_time + self.cruise_time {
+            ramp_distance + self.velocity * (t - self.ramp_time)
+        } else {
+            self.distance - self.ramp_position(duration - t)
+        }
+    }
+}
+
+/// Trajectoire de plusieurs servos, suivant tous le même profil pour arriver ensemble
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    /// (ID, position de départ, position d'arrivée) pour chaque servo
+    moves: Vec<(u8, u16, u16)>,
+    profile: Profile,
+}
+
+impl Trajectory {
+    /// Calculer une trajectoire
+    /// moves: (ID, position de départ, position d'arrivée) pour chaque servo
+    pub fn new(moves: &[(u8, u16, u16)], kind: ProfileKind, limits: &TrajectoryLimits) -> Result<Self, String> {
+        let valid = |limit: f64| limit.is_finite() && limit > 0.0;
+        if !valid(limits.max_velocity)
+            || !valid(limits.max_acceleration)
+            || (kind == ProfileKind::SCurve && !valid(limits.max_jerk))
+        {
+            return Err("Trajectory limits must be finite and positive".to_string());
+        }
+
+        let distance = moves
+            .iter()
+            .map(|&(_, start, target)| (target as f64 - start as f64).abs())
+            .fold(0.0, f64::max);
+
+        Ok(Self {
+            moves: moves.to_vec(),
+            profile: Profile::new(distance, kind, limits),
+        })
+    }
+
+    /// IDs des servos de la trajectoire
+    pub fn ids(&self) -> Vec<u8> {
+        self.moves.iter().map(|&(sts_id, ..)| sts_id).collect()
+    }
+
+    /// (ID, position de départ, position d'arrivée) pour chaque servo
+    pub fn moves(&self) -> &[(u8, u16, u16)] {
+        &self.moves
+    }
+
+    /// Durée totale de la trajectoire
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.profile.duration())
+    }
+
+    /// Consignes de position de chaque servo à un instant donné
+    pub fn sample(&self, t: Duration) -> Vec<(u8, u16)> {
+        let fraction = if self.profile.distance > 0.0 {
+            self.profile.position(t.as_secs_f64()) / self.profile.distance
+        } else {
+            1.0
+        };
+
+        self.moves
+            .iter()
+            .map(|&(sts_id, start, target)| {
+                let position = start as f64 + (target as f64 - start as f64) * fraction;
+                (sts_id, position.round() as u16)
+            })
+            .collect()
+    }
+}
+
+/// État d'une trajectoire diffusée
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TrajectoryStatus {
+    /// Pas encore démarrée
+    #[default]
+    Idle,
+    Running,
+    Paused,
+    /// Toutes les consignes ont été envoyées
+    Finished,
+    Aborted,
+    /// Échec de l'envoi d'une consigne
+    Failed(String),
+}
+
+impl TrajectoryStatus {
+    /// La diffusion est-elle terminée (avec ou sans succès) ?
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::Finished | Self::Aborted | Self::Failed(_))
+    }
+}
+
+/// Avancement d'une trajectoire diffusée
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrajectoryProgress {
+    pub status: TrajectoryStatus,
+    /// Temps écoulé sur la trajectoire (hors pauses)
+    pub elapsed: Duration,
+    pub duration: Duration,
+    /// Dernières consignes envoyées
+    pub setpoints: Vec<(u8, u16)>,
+}
+
+impl TrajectoryProgress {
+    /// Avancement entre 0 et 1
+    pub fn fraction(&self) -> f32 {
+        if self.duration.is_zero() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Run,
+    Pause,
+    Abort,
+}
+
+/// Diffusion d'une trajectoire à cadence fixe sur un thread dédié (voir `ST3215::trajectory_stream`)
+///
+/// Une pause fige la consigne: le servo s'arrête sur la dernière position envoyée.
+pub struct TrajectoryStream {
+    controller: ST3215,
+    trajectory: Trajectory,
+    period: Duration,
+    command: Arc<Mutex<Command>>,
+    progress: Arc<Mutex<TrajectoryProgress>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TrajectoryStream {
+    pub(crate) fn new(controller: ST3215, trajectory: Trajectory, period: Duration) -> Self {
+        let progress = TrajectoryProgress {
+            duration: trajectory.duration(),
+            ..TrajectoryProgress::default()
+        };
+        Self {
+            controller,
+            trajectory,
+            period,
+            command: Arc::new(Mutex::new(Command::Run)),
+            progress: Arc::new(Mutex::new(progress)),
+            thread: None,
+        }
+    }
+
+    /// Démarrer la diffusion
+    /// Une diffusion ne démarre qu'une fois: une fois terminée, créer un nouveau flux
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.thread.is_some() || self.progress.lock().unwrap().status != TrajectoryStatus::Idle {
+            return Err("Trajectory stream already started or finished".to_string());
+        }
+
+        let controller = self.controller.clone();
+        let trajectory = self.trajectory.clone();
+        let period = self.period;
+        let command = Arc::clone(&self.command);
+        let progress = Arc::clone(&self.progress);
+
+        self.thread = Some(thread::spawn(move || {
+            let duration = trajectory.duration();
+            let mut elapsed = Duration::ZERO;
+            let mut last = Instant::now();
+
+            loop {
+                let tick = Instant::now();
+                let running = match *command.lock().unwrap() {
+                    Command::Abort => {
+                        progress.lock().unwrap().status = TrajectoryStatus::Aborted;
+                        break;
+                    }
+                    Command::Pause => false,
+                    Command::Run => true,
+                };
+
+                if running {
+                    elapsed = (elapsed + tick.duration_since(last)).min(duration);
+                    let setpoints = trajectory.sample(elapsed);
+                    if let Err(e) = controller.sync_write_positions(&setpoints) {
+                        progress.lock().unwrap().status = TrajectoryStatus::Failed(e);
+                        break;
+                    }
+
+                    let mut progress = progress.lock().unwrap();
+                    progress.elapsed = elapsed;
+                    progress.setpoints = setpoints;
+                    if elapsed >= duration {
+                        progress.status = TrajectoryStatus::Finished;
+                        break;
+                    }
+                    progress.status = TrajectoryStatus::Running;
+                } else {
+                    progress.lock().unwrap().status = TrajectoryStatus::Paused;
+                }
+                last = tick;
+
+                thread::sleep(period.saturating_sub(tick.elapsed()));
+            }
+        }));
+        Ok(())
+    }
+
+    /// Suspendre la diffusion (le temps de la trajectoire n'avance plus)
+    pub fn pause(&self) {
+        let mut command = self.command.lock().unwrap();
+        if *command == Command::Run {
+            *command = Command::Pause;
+        }
+    }
+
+    /// Reprendre la diffusion après une pause
+    pub fn resume(&self) {
+        let mut command = self.command.lock().unwrap();
+        if *command == Command::Pause {
+            *command = Command::Run;
+        }
+    }
+
+    /// Interrompre la diffusion: les servos restent sur la dernière consigne envoyée
+    pub fn abort(&self) {
+        *self.command.lock().unwrap() = Command::Abort;
+    }
+
+    pub fn progress(&self) -> TrajectoryProgress {
+        self.progress.lock().unwrap().clone()
+    }
+
+    pub fn trajectory(&self) -> &Trajectory {
+        &self.trajectory
+    }
+
+    /// Attendre la fin de la diffusion
+    pub fn wait(&mut self) -> TrajectoryProgress {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        self.progress()
+    }
+}
+
+impl Drop for TrajectoryStream {
+    fn drop(&mut self) {
+        self.abort();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_profile(distance: f64, kind: ProfileKind) {
+        let limits = TrajectoryLimits::default();
+        let profile = Profile::new(distance, kind, &limits);
+        let duration = profile.duration();
+        assert!(profile.velocity <= limits.max_velocity + 1e-9);
+
+        assert_eq!(profile.position(0.0), 0.0);
+        assert!((profile.position(duration) - distance).abs() < 1e-6);
+        assert!((profile.position(duration / 2.0) - distance / 2.0).abs() < 1e-6 * distance.max(1.0));
+
+        // Pas de saut: la position avance au plus à la vitesse de palier
+        let steps = 2000;
+        let dt = duration / steps as f64;
+        let mut previous = 0.0;
+        for i in 1..=steps {
+            let position = profile.position(i as f64 * dt);
+            assert!(position >= previous - 1e-9, "{:?} recule à t={}", kind, i as f64 * dt);
+            assert!(position - previous <= profile.velocity * dt * 1.001 + 1e-9);
+            previous = position;
+        }
+    }
+
+    #[test]
+    fn profiles_are_continuous_and_reach_the_target() {
+        for kind in [ProfileKind::Trapezoidal, ProfileKind::SCurve] {
+            // Palier atteint, puis distance trop courte pour l'atteindre
+            check_profile(3000.0, kind);
+            check_profile(50.0, kind);
+        }
+    }
+
+    #[test]
+    fn scurve_is_slower_than_trapezoid() {
+        let limits = TrajectoryLimits::default();
+        let trapezoid = Profile::new(3000.0, ProfileKind::Trapezoidal, &limits);
+        let scurve = Profile::new(3000.0, ProfileKind::SCurve, &limits);
+        assert!(scurve.duration() > trapezoid.duration());
+        // Trapèze: 3000 / 2000 + 2000 / 4000
+        assert!((trapezoid.duration() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn servos_arrive_together() {
+        let trajectory = Trajectory::new(
+            &[(1, 1000, 3000), (2, 2000, 1500), (3, 500, 500)],
+            ProfileKind::SCurve,
+            &TrajectoryLimits::default(),
+        )
+        .unwrap();
+
+        assert_eq!(trajectory.sample(Duration::ZERO), vec![(1, 1000), (2, 2000), (3, 500)]);
+        assert_eq!(trajectory.sample(trajectory.duration()), vec![(1, 3000), (2, 1500), (3, 500)]);
+        assert_eq!(trajectory.sample(trajectory.duration() / 2), vec![(1, 2000), (2, 1750), (3, 500)]);
+    }
+
+    #[test]
+    fn limits_must_be_finite_and_positive() {
+        let limits = TrajectoryLimits {
+            max_jerk: 0.0,
+            ..TrajectoryLimits::default()
+        };
+        assert!(Trajectory::new(&[(1, 0, 100)], ProfileKind::SCurve, &limits).is_err());
+        assert!(Trajectory::new(&[(1, 0, 100)], ProfileKind::Trapezoidal, &limits).is_ok());
+
+        for invalid in [f64::NAN, f64::INFINITY, -1.0] {
+            let velocity = TrajectoryLimits {
+                max_velocity: invalid,
+                ..TrajectoryLimits::default()
+            };
+            let acceleration = TrajectoryLimits {
+                max_acceleration: invalid,
+                ..TrajectoryLimits::default()
+            };
+            let jerk = TrajectoryLimits {
+                max_jerk: invalid,
+                ..TrajectoryLimits::default()
+            };
+            assert!(Trajectory::new(&[(1, 0, 100)], ProfileKind::Trapezoidal, &velocity).is_err());
+            assert!(Trajectory::new(&[(1, 0, 100)], ProfileKind::Trapezoidal, &acceleration).is_err());
+            assert!(Trajectory::new(&[(1, 0, 100)], ProfileKind::SCurve, &jerk).is_err());
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+/// Coefficients de la boucle de position (registres EEPROM `STS_P_COEF`, `STS_I_COEF`, `STS_D_COEF`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PidGains {
+    pub p: u8,
+    pub i: u8,
+    pub d: u8,
+}
+
+/// Mesure de la réponse à un échelon de position
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StepResponse {
+    pub start: u16,
+    pub target: u16,
+    /// Positions échantillonnées, datées depuis l'envoi de la consigne
+    pub samples: Vec<(Duration, u16)>,
+    /// Temps de montée de 10 % à 90 % de l'échelon (None si 90 % n'est jamais atteint)
+    pub rise_time: Option<Duration>,
+    /// Dépassement maximal, en pourcentage de l'échelon
+    pub overshoot: f32,
+    /// Instant à partir duquel la position reste dans la tolérance (None si jamais stabilisée)
+    pub settling_time: Option<Duration>,
+    /// Écart final entre la position et la consigne (en steps)
+    pub steady_state_error: i32,
+}
+
+impl StepResponse {
+    /// Calculer les caractéristiques d'une réponse à partir des échantillons
+    /// tolerance: écart à la consigne considéré comme stabilisé (en steps)
+    pub fn analyze(start: u16, target: u16, samples: Vec<(Duration, u16)>, tolerance: u16) -> Self {
+        let step = target as f32 - start as f32;
+        let progress = |position: u16| {
+            if step == 0.0 {
+                1.0
+            } else {
+                (position as f32 - start as f32) / step
+            }
+        };
+
+        let crossing = |level: f32| samples.iter().find(|(_, pos)| progress(*pos) >= level).map(|(t, _)| *t);
+        let rise_time = match (crossing(0.1), crossing(0.9)) {
+            (Some(t10), Some(t90)) => Some(t90.saturating_sub(t10)),
+            _ => None,
+        };
+
+        let peak = samples.iter().map(|(_, pos)| progress(*pos)).fold(0.0_f32, f32::max);
+        let overshoot = ((peak - 1.0) * 100.0).max(0.0);
+
+        let outside = |position: u16| (position as i32 - target as i32).unsigned_abs() > tolerance as u32;
+        let settling_time = match samples.iter().rposition(|(_, pos)| outside(*pos)) {
+            None => samples.first().map(|(t, _)| *t),
+            Some(last) => samples.get(last + 1).map(|(t, _)| *t),
+        };
+
+        let steady_state_error = samples
+            .last()
+            .map(|(_, pos)| *pos as i32 - target as i32)
+            .unwrap_or(0);
+
+        Self {
+            start,
+            target,
+            samples,
+            rise_time,
+            overshoot,
+            settling_time,
+            steady_state_error,
+        }
+    }
+}
+
+/// Paramètres de l'ajustement automatique des gains
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningOptions {
+    /// Position de départ de l'échelon
+    pub from: u16,
+    /// Position d'arrivée de l'échelon
+    pub to: u16,
+    /// Dépassement maximal accepté (en %)
+    pub max_overshoot: f32,
+    /// Temps de montée maximal accepté
+    pub max_rise_time: Duration,
+    /// Écart à la consigne considéré comme stabilisé (en steps)
+    pub tolerance: u16,
+    /// Durée d'enregistrement de chaque échelon
+    pub sample_duration: Duration,
+    /// Période d'échantillonnage de la position
+    pub sample_period: Duration,
+    /// Nombre maximal d'essais (chaque essai réécrit les gains en EEPROM)
+    pub max_iterations: u32,
+}
+
+impl Default for TuningOptions {
+    fn default() -> Self {
+        Self {
+            from: 1548,
+            to: 2548,
+            max_overshoot: 5.0,
+            max_rise_time: Duration::from_millis(400),
+            tolerance: 10,
+            sample_duration: Duration::from_secs(1),
+            sample_period: Duration::from_millis(5),
+            max_iterations: 6,
+        }
+    }
+}
+
+impl TuningOptions {
+    /// La réponse satisfait-elle les objectifs ?
+    pub fn is_satisfied(&self, response: &StepResponse) -> bool {
+        response.overshoot <= self.max_overshoot
+            && response.settling_time.is_some()
+            && response.rise_time.is_some_and(|rise| rise <= self.max_rise_time)
+            && response.steady_state_error.unsigned_abs() <= self.tolerance as u32
+    }
+
+    /// Proposer de nouveaux gains à partir d'une réponse mesurée
+    /// Retourne None si les objectifs sont déjà atteints
+    ///
+    /// - Dépassement ou oscillation: P réduit de 20 %, D augmenté
+    /// - Réponse trop lente: P augmenté de 20 %
+    /// - Erreur statique: I augmenté
+    pub fn suggest(&self, gains: PidGains, response: &StepResponse) -> Option<PidGains> {
+        if self.is_satisfied(response) {
+            return None;
+        }
+
+        let scale = |value: u8, factor: f32| ((value as f32 * factor).round() as u8).clamp(1, 254);
+        let mut next = gains;
+        if response.overshoot > self.max_overshoot || response.settling_time.is_none() {
+            next.p = scale(gains.p, 0.8);
+            next.d = gains.d.saturating_add((gains.d / 4).max(1)).min(254);
+        } else if response.rise_time.is_none_or(|rise| rise > self.max_rise_time) {
+            next.p = scale(gains.p, 1.2).max(gains.p.saturating_add(1)).min(254);
+        } else {
+            next.i = gains.i.saturating_add(1).min(254);
+        }
+
+        if next == gains {
+            None
+        } else {
+            Some(next)
+        }
+    }
+}
+
+/// Résultat de l'ajustement automatique des gains
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningResult {
+    /// Gains retenus (écrits sur le servo)
+    pub gains: PidGains,
+    /// Réponse mesurée avec ces gains
+    pub response: StepResponse,
+    /// Les objectifs ont-ils été atteints ?
+    pub satisfied: bool,
+    /// Gains essayés et réponse correspondante, dans l'ordre
+    pub history: Vec<(PidGains, StepResponse)>,
+}
+
+/// Paramètres de la caractérisation de la zone morte
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadbandOptions {
+    /// Plus grand échelon essayé (en steps)
+    pub max_step: u16,
+    /// Attente après chaque consigne avant de relever la position
+    pub settle_time: Duration,
+    /// Nombre de lectures au repos pour mesurer le bruit de position
+    pub jitter_samples: usize,
+}
+
+impl Default for DeadbandOptions {
+    fn default() -> Self {
+        Self {
+            max_step: 20,
+            settle_time: Duration::from_millis(200),
+            jitter_samples: 30,
+        }
+    }
+}
+
+/// Résultat de la caractérisation de la zone morte
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeadbandReport {
+    /// Plus petit échelon suivi d'un mouvement, vers les positions décroissantes (CW)
+    pub cw_threshold: Option<u16>,
+    /// Plus petit échelon suivi d'un mouvement, vers les positions croissantes (CCW)
+    pub ccw_threshold: Option<u16>,
+    /// Variation crête à crête de la position au repos (en steps)
+    pub jitter: u16,
+    /// Zones mortes recommandées (CW, CCW)
+    pub recommended: (u8, u8),
+}
+
+impl DeadbandReport {
+    /// Recommander des zones mortes à partir des mesures
+    ///
+    /// Sous le seuil de réponse, le moteur force sans bouger et le servo vibre: la zone morte
+    /// recouvre l'intervalle juste en dessous du seuil, et au moins la moitié du bruit de position.
+    /// Une zone plus large réduirait inutilement la précision.
+    pub fn recommend(threshold: Option<u16>, jitter: u16, max_step: u16) -> u8 {
+        let below_threshold = threshold.unwrap_or(max_step).saturating_sub(1);
+        below_threshold.max(jitter.div_ceil(2)).min(32) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn response(overshoot: f32, rise_time: Option<Duration>, steady_state_error: i32) -> StepResponse {
+        StepResponse {
+            rise_time,
+            overshoot,
+            settling_time: Some(ms(200)),
+            steady_state_error,
+            ..StepResponse::default()
+        }
+    }
+
+    #[test]
+    fn analyze_measures_rise_overshoot_and_settling() {
+        let positions = [1000, 1050, 1200, 1500, 1800, 1950, 2080, 2010, 2000, 2002];
+        let samples = positions
+            .iter()
+            .enumerate()
+            .map(|(i, &pos)| (ms(10 * i as u64), pos))
+            .collect();
+
+        let response = StepResponse::analyze(1000, 2000, samples, 10);
+        assert_eq!(response.rise_time, Some(ms(30)));
+        assert!((response.overshoot - 8.0).abs() < 1e-3);
+        assert_eq!(response.settling_time, Some(ms(70)));
+        assert_eq!(response.steady_state_error, 2);
+    }
+
+    #[test]
+    fn analyze_handles_downward_step_that_never_settles() {
+        let samples = vec![(ms(0), 3000), (ms(10), 2500), (ms(20), 2200)];
+
+        let response = StepResponse::analyze(3000, 2000, samples, 10);
+        assert_eq!(response.rise_time, None);
+        assert_eq!(response.overshoot, 0.0);
+        assert_eq!(response.settling_time, None);
+        assert_eq!(response.steady_state_error, 200);
+    }
+
+    #[test]
+    fn suggest_follows_tuning_rules() {
+        let options = TuningOptions::default();
+        let gains = PidGains { p: 30, i: 0, d: 10 };
+
+        let overshoot = options.suggest(gains, &response(8.0, Some(ms(100)), 0));
+        assert_eq!(overshoot, Some(PidGains { p: 24, i: 0, d: 12 }));
+
+        let slow = options.suggest(gains, &response(0.0, Some(ms(500)), 0));
+        assert_eq!(slow, Some(PidGains { p: 36, ..gains }));
+
+        let offset = options.suggest(gains, &response(0.0, Some(ms(100)), 20));
+        assert_eq!(offset, Some(PidGains { i: 1, ..gains }));
+
+        assert_eq!(options.suggest(gains, &response(0.0, Some(ms(100)), 0)), None);
+    }
+
+    #[test]
+    fn recommend_covers_the_band_below_threshold() {
+        assert_eq!(DeadbandReport::recommend(Some(4), 2, 20), 3);
+        assert_eq!(DeadbandReport::recommend(Some(1), 5, 20), 3);
+        assert_eq!(DeadbandReport::recommend(None, 0, 20), 19);
+        assert_eq!(DeadbandReport::recommend(None, 0, 100), 32);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::models::ModelSpec;
+use std::f32::consts::PI;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+/// Angle (stocké en radians)
+/// La position 0 du servo correspond à l'angle 0
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Angle(f32);
+
+/// Vitesse angulaire (stockée en rad/s)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AngularVelocity(f32);
+
+/// Accélération angulaire (stockée en rad/s²)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AngularAcceleration(f32);
+
+/// Radians parcourus par step pour un modèle donné
+fn radians_per_step(spec: &ModelSpec) -> f32 {
+    2.0 * PI / spec.steps_per_revolution()
+}
+
+impl Angle {
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    pub fn from_radians(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// Angle correspondant à une position (en steps) pour un modèle donné
+    pub fn from_steps(steps: f32, spec: &ModelSpec) -> Self {
+        Self(steps * radians_per_step(spec))
+    }
+
+    pub fn degrees(&self) -> f32 {
+        self.0.to_degrees()
+    }
+
+    pub fn radians(&self) -> f32 {
+        self.0
+    }
+
+    /// Position correspondante (en steps, arrondie) pour un modèle donné
+    pub fn to_steps(&self, spec: &ModelSpec) -> f32 {
+        (self.0 / radians_per_step(spec)).round()
+    }
+}
+
+impl AngularVelocity {
+    pub fn from_degrees_per_sec(degrees: f32) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    pub fn from_radians_per_sec(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// Vitesse en tours par minute
+    pub fn from_rpm(rpm: f32) -> Self {
+        Self(rpm * 2.0 * PI / 60.0)
+    }
+
+    /// Vitesse correspondant à une valeur en step/s pour un modèle donné
+    pub fn from_steps_per_sec(steps: f32, spec: &ModelSpec) -> Self {
+        Self(steps * radians_per_step(spec))
+    }
+
+    pub fn degrees_per_sec(&self) -> f32 {
+        self.0.to_degrees()
+    }
+
+    pub fn radians_per_sec(&self) -> f32 {
+        self.0
+    }
+
+    pub fn rpm(&self) -> f32 {
+        self.0 * 60.0 / (2.0 * PI)
+    }
+
+    /// Vitesse en step/s (arrondie) pour un modèle donné
+    pub fn to_steps_per_sec(&self, spec: &ModelSpec) -> f32 {
+        (self.0 / radians_per_step(spec)).round()
+    }
+}
+
+impl AngularAcceleration {
+    pub fn from_degrees_per_sec2(degrees: f32) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    pub fn from_radians_per_sec2(radians: f32) -> Self {
+        Self(radians)
+    }
+
+    /// Accélération correspondant à une valeur du registre `STS_ACC` (unité: 100 step/s²)
+    pub fn from_register(acc: u8, spec: &ModelSpec) -> Self {
+        Self(acc as f32 * 100.0 * radians_per_step(spec))
+    }
+
+    pub fn degrees_per_sec2(&self) -> f32 {
+        self.0.to_degrees()
+    }
+
+    pub fn radians_per_sec2(&self) -> f32 {
+        self.0
+    }
+
+    /// Valeur du registre `STS_ACC` (unité: 100 step/s², arrondie et bornée à 0..=254)
+    pub fn to_register(&self, spec: &ModelSpec) -> u8 {
+        (self.0 / radians_per_step(spec) / 100.0).round().clamp(0.0, 254.0) as u8
+    }
+}
+
+macro_rules! impl_arithmetic {
+    ($unit:ty) => {
+        impl Add for $unit {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+impl_arithmetic!(Angle);
+impl_arithmetic!(AngularVelocity);
+impl_arithmetic!(AngularAcceleration);
+
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}°", self.degrees())
+    }
+}
+
+impl fmt::Display for AngularVelocity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}°/s", self.degrees_per_sec())
+    }
+}
+
+impl fmt::Display for AngularAcceleration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}°/s²", self.degrees_per_sec2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::ProtocolVariant;
+
+    fn sts() -> &'static ModelSpec {
+        ModelSpec::for_protocol(ProtocolVariant::Sts)
+    }
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn angle_converts_to_and_from_steps() {
+        assert_eq!(Angle::from_degrees(90.0).to_steps(sts()), 1024.0);
+        assert_eq!(Angle::from_degrees(-45.0).to_steps(sts()), -512.0);
+        assert!(close(Angle::from_steps(2048.0, sts()).degrees(), 180.0));
+        assert!(close(Angle::from_radians(PI).degrees(), 180.0));
+    }
+
+    #[test]
+    fn velocity_converts_between_units() {
+        let velocity = AngularVelocity::from_rpm(60.0);
+        assert!(close(velocity.degrees_per_sec(), 360.0));
+        assert!(close(velocity.rpm(), 60.0));
+        assert_eq!(velocity.to_steps_per_sec(sts()), 4096.0);
+        assert!(close(AngularVelocity::from_steps_per_sec(2048.0, sts()).rpm(), 30.0));
+    }
+
+    #[test]
+    fn acceleration_register_round_trips_and_saturates() {
+        let acceleration = AngularAcceleration::from_register(50, sts());
+        assert_eq!(acceleration.to_register(sts()), 50);
+        assert_eq!(AngularAcceleration::from_degrees_per_sec2(1e6).to_register(sts()), 254);
+        assert_eq!(AngularAcceleration::from_degrees_per_sec2(-100.0).to_register(sts()), 0);
+    }
+
+    #[test]
+    fn arithmetic_and_display() {
+        let angle = Angle::from_degrees(30.0) + Angle::from_degrees(60.0);
+        assert!(close(angle.degrees(), 90.0));
+        assert!(close((-angle).degrees(), -90.0));
+        assert!(close((angle - Angle::from_degrees(90.0)).degrees(), 0.0));
+        assert_eq!(angle.to_string(), "90.00°");
+        assert_eq!(AngularVelocity::from_degrees_per_sec(12.5).to_string(), "12.50°/s");
+    }
+}
@@ -6,6 +6,7 @@ pub const LATENCY_TIMER: f64 = 50.0;
 pub const TXPACKET_MAX_LEN: usize = 250;
 pub const RXPACKET_MAX_LEN: usize = 250;
 
+// Limites du STS3215; les autres modèles sont décrits par ModelSpec (ST3215::capabilities)
 pub const MIN_POSITION: u16 = 0;
 pub const MAX_POSITION: u16 = 4095;
 
@@ -31,6 +32,7 @@ pub const ERRBIT_OVERLOAD: u8 = 32;
 pub const BROADCAST_ID: u8 = 0xFE; // 254
 pub const MAX_ID: u8 = 0xFC; // 252
 pub const STS_END: u8 = 0;
+pub const SCS_END: u8 = 1;
 
 // Instructions pour le protocole STS
 pub const INST_PING: u8 = 1;
@@ -55,6 +57,140 @@ pub enum CommResult {
     NotAvailable = -9,
 }
 
+// Variante du protocole Feetech
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtocolVariant {
+    /// Série STS/SMS (little-endian), ex: STS3215
+    #[default]
+    Sts,
+    /// Série SCS (big-endian), ex: SCS0009, SCS15
+    Scs,
+}
+
+impl ProtocolVariant {
+    /// Ordre des octets utilisé par `sts_makeword`/`sts_lobyte`
+    pub fn end(&self) -> u8 {
+        match self {
+            ProtocolVariant::Sts => STS_END,
+            ProtocolVariant::Scs => SCS_END,
+        }
+    }
+
+    pub fn min_position(&self) -> u16 {
+        match self {
+            ProtocolVariant::Sts => MIN_POSITION,
+            ProtocolVariant::Scs => SCS_MIN_POSITION,
+        }
+    }
+
+    pub fn max_position(&self) -> u16 {
+        match self {
+            ProtocolVariant::Sts => MAX_POSITION,
+            ProtocolVariant::Scs => SCS_MAX_POSITION,
+        }
+    }
+
+    pub fn max_speed(&self) -> u16 {
+        match self {
+            ProtocolVariant::Sts => MAX_SPEED,
+            ProtocolVariant::Scs => SCS_MAX_SPEED,
+        }
+    }
+
+    /// Adresse du registre de verrouillage de l'EEPROM
+    pub fn lock_address(&self) -> u8 {
+        match self {
+            ProtocolVariant::Sts => STS_LOCK,
+            ProtocolVariant::Scs => SCS_LOCK,
+        }
+    }
+
+    /// Les servos SCS n'ont ni registre d'accélération, ni mode, ni correction
+    pub fn has_acceleration(&self) -> bool {
+        matches!(self, ProtocolVariant::Sts)
+    }
+
+    pub fn has_mode(&self) -> bool {
+        matches!(self, ProtocolVariant::Sts)
+    }
+
+    pub fn has_offset(&self) -> bool {
+        matches!(self, ProtocolVariant::Sts)
+    }
+}
+
+// Niveau de réponse du servo (registre STS_RESPONSE_LEVEL)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseLevel {
+    /// Le servo ne répond qu'aux instructions PING et READ
+    ReadOnly = 0,
+    /// Le servo répond à toutes les instructions (valeur d'usine)
+    #[default]
+    All = 1,
+}
+
+impl ResponseLevel {
+    pub fn from_u8(value: u8) -> Self {
+        if value == 0 {
+            ResponseLevel::ReadOnly
+        } else {
+            ResponseLevel::All
+        }
+    }
+}
+
+// Contrôle côté hôte des consignes de position par rapport aux limites d'angle du servo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitEnforcement {
+    /// Consignes transmises telles quelles (le servo lève ERRBIT_ANGLE)
+    #[default]
+    Disabled,
+    /// Consignes hors limites refusées avant envoi
+    Reject,
+    /// Consignes ramenées dans les limites
+    Clamp,
+}
+
+// Préréglages de souplesse, appliqués par la limite de couple (registre STS_TORQUE_LIMIT_L/H)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Compliance {
+    /// Couple maximal: le servo tient sa position
+    #[default]
+    Stiff,
+    /// Couple réduit: cède sous un effort modéré (interaction, préhension douce)
+    Soft,
+    /// Couple minimal: se laisse déplacer à la main mais tient contre son propre poids
+    LimpHolding,
+    /// Limite de couple personnalisée (en %)
+    Custom(f32),
+}
+
+impl Compliance {
+    /// Limite de couple correspondante (en %)
+    pub fn torque_limit(&self) -> f32 {
+        match *self {
+            Compliance::Stiff => 100.0,
+            Compliance::Soft => 35.0,
+            Compliance::LimpHolding => 10.0,
+            Compliance::Custom(percent) => percent,
+        }
+    }
+
+    /// Retrouver un préréglage par son nom ("stiff", "soft", "limp_holding")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "stiff" => Some(Compliance::Stiff),
+            "soft" => Some(Compliance::Soft),
+            "limp_holding" => Some(Compliance::LimpHolding),
+            _ => None,
+        }
+    }
+}
+
+// Unité du registre STS_RETURN_DELAY (µs)
+pub const RETURN_DELAY_UNIT_US: u32 = 2;
+pub const MAX_RETURN_DELAY_US: u32 = 254 * RETURN_DELAY_UNIT_US;
+
 impl CommResult {
     pub fn is_success(&self) -> bool {
         matches!(self, CommResult::Success)
@@ -83,22 +219,63 @@ pub const STS_57600: u8 = 6;
 #[allow(dead_code)]
 pub const STS_38400: u8 = 7;
 
+// Débit (en bauds) correspondant à une valeur du registre STS_BAUD_RATE
+pub fn baud_rate_bps(code: u8) -> Option<u32> {
+    match code {
+        STS_1M => Some(1_000_000),
+        STS_0_5M => Some(500_000),
+        STS_250K => Some(250_000),
+        STS_128K => Some(128_000),
+        STS_115200 => Some(115_200),
+        STS_76800 => Some(76_800),
+        STS_57600 => Some(57_600),
+        STS_38400 => Some(38_400),
+        _ => None,
+    }
+}
+
 // EPROM RO
+pub const STS_FIRMWARE_MAJOR: u8 = 0;
+pub const STS_FIRMWARE_MINOR: u8 = 1;
 pub const STS_MODEL_L: u8 = 3;
 pub const STS_MODEL_H: u8 = 4;
 
 // EPROM RW
 pub const STS_ID: u8 = 5;
 pub const STS_BAUD_RATE: u8 = 6;
+pub const STS_RETURN_DELAY: u8 = 7;
+pub const STS_RESPONSE_LEVEL: u8 = 8;
 pub const STS_MIN_ANGLE_LIMIT_L: u8 = 9;
 pub const STS_MIN_ANGLE_LIMIT_H: u8 = 10;
 pub const STS_MAX_ANGLE_LIMIT_L: u8 = 11;
 pub const STS_MAX_ANGLE_LIMIT_H: u8 = 12;
+pub const STS_MAX_TEMPERATURE: u8 = 13;
+pub const STS_MAX_VOLTAGE: u8 = 14;
+pub const STS_MIN_VOLTAGE: u8 = 15;
+pub const STS_MAX_TORQUE_L: u8 = 16;
+pub const STS_MAX_TORQUE_H: u8 = 17;
+pub const STS_PHASE: u8 = 18;
+pub const STS_UNLOADING_CONDITION: u8 = 19;
+pub const STS_LED_ALARM: u8 = 20;
+pub const STS_P_COEF: u8 = 21;
+pub const STS_D_COEF: u8 = 22;
+pub const STS_I_COEF: u8 = 23;
+pub const STS_MIN_STARTUP_FORCE_L: u8 = 24;
+pub const STS_MIN_STARTUP_FORCE_H: u8 = 25;
 pub const STS_CW_DEAD: u8 = 26;
 pub const STS_CCW_DEAD: u8 = 27;
+pub const STS_PROTECTION_CURRENT_L: u8 = 28;
+pub const STS_PROTECTION_CURRENT_H: u8 = 29;
+pub const STS_ANGULAR_RESOLUTION: u8 = 30;
 pub const STS_OFS_L: u8 = 31;
 pub const STS_OFS_H: u8 = 32;
 pub const STS_MODE: u8 = 33;
+pub const STS_PROTECTIVE_TORQUE: u8 = 34;
+pub const STS_PROTECTION_TIME: u8 = 35;
+pub const STS_OVERLOAD_TORQUE: u8 = 36;
+pub const STS_SPEED_P_COEF: u8 = 37;
+pub const STS_OVERCURRENT_PROTECTION_TIME: u8 = 38;
+pub const STS_SPEED_I_COEF: u8 = 39;
 
 // SRAM RW
 pub const STS_TORQUE_ENABLE: u8 = 40;
@@ -109,6 +286,8 @@ pub const STS_GOAL_TIME_L: u8 = 44;
 pub const STS_GOAL_TIME_H: u8 = 45;
 pub const STS_GOAL_SPEED_L: u8 = 46;
 pub const STS_GOAL_SPEED_H: u8 = 47;
+pub const STS_TORQUE_LIMIT_L: u8 = 48;
+pub const STS_TORQUE_LIMIT_H: u8 = 49;
 pub const STS_LOCK: u8 = 55;
 
 // SRAM RO
@@ -120,7 +299,52 @@ pub const STS_PRESENT_LOAD_L: u8 = 60;
 pub const STS_PRESENT_LOAD_H: u8 = 61;
 pub const STS_PRESENT_VOLTAGE: u8 = 62;
 pub const STS_PRESENT_TEMPERATURE: u8 = 63;
+pub const STS_ASYNC_WRITE_FLAG: u8 = 64;
 pub const STS_STATUS: u8 = 65;
 pub const STS_MOVING: u8 = 66;
 pub const STS_PRESENT_CURRENT_L: u8 = 69;
 pub const STS_PRESENT_CURRENT_H: u8 = 70;
+
+// Table de contrôle SCS (SCS0009, SCS15, ...)
+pub const SCS_MIN_POSITION: u16 = 0;
+pub const SCS_MAX_POSITION: u16 = 1023;
+pub const SCS_MAX_SPEED: u16 = 1500;
+
+// EPROM RO
+pub const SCS_VERSION_L: u8 = 3;
+pub const SCS_VERSION_H: u8 = 4;
+
+// EPROM RW
+pub const SCS_ID: u8 = 5;
+pub const SCS_BAUD_RATE: u8 = 6;
+pub const SCS_RETURN_DELAY: u8 = 7;
+pub const SCS_RESPONSE_LEVEL: u8 = 8;
+pub const SCS_MIN_ANGLE_LIMIT_L: u8 = 9;
+pub const SCS_MIN_ANGLE_LIMIT_H: u8 = 10;
+pub const SCS_MAX_ANGLE_LIMIT_L: u8 = 11;
+pub const SCS_MAX_ANGLE_LIMIT_H: u8 = 12;
+pub const SCS_CW_DEAD: u8 = 26;
+pub const SCS_CCW_DEAD: u8 = 27;
+
+// SRAM RW
+pub const SCS_TORQUE_ENABLE: u8 = 40;
+pub const SCS_GOAL_POSITION_L: u8 = 42;
+pub const SCS_GOAL_POSITION_H: u8 = 43;
+pub const SCS_GOAL_TIME_L: u8 = 44;
+pub const SCS_GOAL_TIME_H: u8 = 45;
+pub const SCS_GOAL_SPEED_L: u8 = 46;
+pub const SCS_GOAL_SPEED_H: u8 = 47;
+pub const SCS_LOCK: u8 = 48;
+
+// SRAM RO
+pub const SCS_PRESENT_POSITION_L: u8 = 56;
+pub const SCS_PRESENT_POSITION_H: u8 = 57;
+pub const SCS_PRESENT_SPEED_L: u8 = 58;
+pub const SCS_PRESENT_SPEED_H: u8 = 59;
+pub const SCS_PRESENT_LOAD_L: u8 = 60;
+pub const SCS_PRESENT_LOAD_H: u8 = 61;
+pub const SCS_PRESENT_VOLTAGE: u8 = 62;
+pub const SCS_PRESENT_TEMPERATURE: u8 = 63;
+pub const SCS_MOVING: u8 = 66;
+pub const SCS_PRESENT_CURRENT_L: u8 = 69;
+pub const SCS_PRESENT_CURRENT_H: u8 = 70;
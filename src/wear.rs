@@ -0,0 +1,218 @@
+use crate::control_table::Register;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Comportement une fois le budget d'écritures EEPROM dépassé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BudgetAction {
+    /// Écrire quand même, et le signaler (voir `ST3215::on_eeprom_budget_exceeded`, ou à défaut
+    /// `ST3215::take_eeprom_warnings`)
+    #[default]
+    Warn,
+    /// Refuser l'écriture
+    Refuse,
+}
+
+/// Nombre d'écritures EEPROM par servo et par registre (nom du registre)
+pub type WearCounters = BTreeMap<u8, BTreeMap<String, u64>>;
+
+/// Suivi de l'usure de l'EEPROM, éventuellement persisté dans un fichier d'état JSON
+#[derive(Debug, Clone, Default)]
+pub struct EepromWear {
+    counters: WearCounters,
+    path: Option<PathBuf>,
+    /// Nombre maximal d'écritures par servo et par registre (None = illimité)
+    pub budget: Option<u64>,
+    pub action: BudgetAction,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WearFile {
+    servos: WearCounters,
+}
+
+impl EepromWear {
+    /// Charger les compteurs depuis un fichier d'état (créé s'il n'existe pas)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let counters = if path.exists() {
+            let text = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+            serde_json::from_str::<WearFile>(&text)
+                .map_err(|e| format!("Invalid wear state file {}: {}", path.display(), e))?
+                .servos
+        } else {
+            WearCounters::new()
+        };
+
+        let wear = Self {
+            counters,
+            path: Some(path.to_path_buf()),
+            ..Self::default()
+        };
+        wear.save()?;
+        Ok(wear)
+    }
+
+    /// Enregistrer les compteurs dans le fichier d'état (sans effet si le suivi n'est pas persisté)
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = WearFile {
+            servos: self.counters.clone(),
+        };
+        let text = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn counters(&self) -> &WearCounters {
+        &self.counters
+    }
+
+    /// Nombre d'écritures d'un registre d'un servo
+    pub fn count(&self, sts_id: u8, register: Register) -> u64 {
+        self.counters
+            .get(&sts_id)
+            .and_then(|registers| registers.get(register.name()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Une écriture supplémentaire dépasserait-elle le budget ?
+    pub fn exceeds_budget(&self, sts_id: u8, register: Register) -> bool {
+        self.budget.is_some_and(|budget| self.count(sts_id, register) >= budget)
+    }
+
+    /// Vérifier qu'une écriture est autorisée par le budget
+    pub fn check(&self, sts_id: u8, register: Register) -> Result<(), String> {
+        if self.action == BudgetAction::Refuse && self.exceeds_budget(sts_id, register) {
+            return Err(format!(
+                "EEPROM write budget exceeded for {} on servo {} ({} writes)",
+                register.name(),
+                sts_id,
+                self.count(sts_id, register)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Comptabiliser une écriture, et mettre à jour le fichier d'état
+    /// Retourne le nouveau nombre d'écritures, ou l'erreur d'écriture du fichier d'état
+    /// (le compteur en mémoire est mis à jour dans tous les cas)
+    pub fn record(&mut self, sts_id: u8, register: Register) -> Result<u64, String> {
+        let count = self
+            .counters
+            .entry(sts_id)
+            .or_default()
+            .entry(register.name().to_string())
+            .or_insert(0);
+        *count += 1;
+        let count = *count;
+        self.save()?;
+        Ok(count)
+    }
+
+    /// Reporter les compteurs d'un servo sur son nouvel ID
+    /// Les compteurs déjà associés au nouvel ID appartenaient à un autre servo: ils sont remplacés
+    /// Retourne l'erreur d'écriture du fichier d'état (les compteurs en mémoire sont reportés)
+    pub fn move_servo(&mut self, old_id: u8, new_id: u8) -> Result<(), String> {
+        if old_id == new_id {
+            return Ok(());
+        }
+        match self.counters.remove(&old_id) {
+            Some(registers) => {
+                self.counters.insert(new_id, registers);
+            }
+            None => {
+                self.counters.remove(&new_id);
+            }
+        }
+        self.save()
+    }
+
+    /// Registres dont le nombre d'écritures a atteint le budget
+    pub fn over_budget(&self) -> Vec<(u8, String, u64)> {
+        let Some(budget) = self.budget else {
+            return Vec::new();
+        };
+        self.counters
+            .iter()
+            .flat_map(|(&sts_id, registers)| {
+                registers
+                    .iter()
+                    .filter(|(_, &count)| count >= budget)
+                    .map(move |(name, &count)| (sts_id, name.clone(), count))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("st3215-wear-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn counters_survive_a_reload() {
+        let path = temp_path("reload");
+        let _ = fs::remove_file(&path);
+
+        let mut wear = EepromWear::load(&path).unwrap();
+        wear.record(1, Register::Id).unwrap();
+        wear.record(1, Register::Id).unwrap();
+        wear.record(2, Register::MaxTemperature).unwrap();
+
+        let reloaded = EepromWear::load(&path).unwrap();
+        assert_eq!(reloaded.counters(), wear.counters());
+        assert_eq!(reloaded.count(1, Register::Id), 2);
+        assert_eq!(reloaded.count(2, Register::MaxTemperature), 1);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn persistence_errors_are_reported() {
+        let dir = temp_path("unwritable");
+        fs::create_dir_all(&dir).unwrap();
+        // Le chemin du fichier d'état est un répertoire: l'écriture échoue
+        let mut wear = EepromWear {
+            path: Some(dir.clone()),
+            ..EepromWear::default()
+        };
+        assert!(wear.record(1, Register::Id).is_err());
+        assert_eq!(wear.count(1, Register::Id), 1);
+        assert!(wear.move_servo(1, 2).is_err());
+        let _ = fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn move_servo_replaces_destination_counters() {
+        let mut wear = EepromWear::default();
+        wear.record(1, Register::Id).unwrap();
+        wear.record(5, Register::MaxTemperature).unwrap();
+        wear.record(5, Register::Id).unwrap();
+
+        wear.move_servo(1, 5).unwrap();
+        assert_eq!(wear.count(5, Register::Id), 1);
+        assert_eq!(wear.count(5, Register::MaxTemperature), 0);
+        assert!(!wear.counters().contains_key(&1));
+    }
+
+    #[test]
+    fn refuse_applies_only_beyond_budget() {
+        let mut wear = EepromWear {
+            budget: Some(2),
+            action: BudgetAction::Refuse,
+            ..EepromWear::default()
+        };
+        wear.record(3, Register::Id).unwrap();
+        assert!(wear.check(3, Register::Id).is_ok());
+        wear.record(3, Register::Id).unwrap();
+        assert!(wear.check(3, Register::Id).is_err());
+        assert_eq!(wear.over_budget(), vec![(3, Register::Id.name().to_string(), 2)]);
+    }
+}